@@ -0,0 +1,154 @@
+//! Generates the in-process solution registry from the solutions in `src/bin`, or the directory
+//! set in `aoc.toml`, and the example tests of every solution from its example manifest.
+use std::{env, fs, path::Path};
+
+#[allow(dead_code, unused_imports)]
+#[path = "src/template/toml.rs"]
+mod toml;
+
+#[allow(dead_code, unused_imports)]
+#[path = "src/template/manifest.rs"]
+mod manifest;
+
+/// A solution in the bin directory.
+struct Bin {
+    /// Name of the module in the registry, e.g. `day01` or `y2021_day01`.
+    module: String,
+    path: String,
+    /// Crate name of the bin, e.g. `01` or `2021_01`, which `example_tests!` uses to find its tests.
+    crate_name: String,
+    /// e.g. `data/examples` or `data/2021/examples`.
+    examples_dir: String,
+    /// e.g. `01.manifest`.
+    manifest: String,
+}
+
+/// Reads a key in the `[paths]` table of `aoc.toml`. The rest of the file is checked by the binary.
+fn configured_path(root: &Path, key: &str) -> Option<String> {
+    let s = fs::read_to_string(root.join("aoc.toml")).ok()?;
+
+    toml::parse_tables(&s)
+        .ok()?
+        .into_iter()
+        .find(|table| table.name == "paths")?
+        .string(key)
+        .ok()?
+        .filter(|dir| !dir.is_empty())
+}
+
+/// Reruns the build script when `path` changes, or its closest ancestor that exists, so that
+/// creating the path is noticed without rerunning on every build.
+fn watch(root: &Path, path: &str) {
+    if let Some(existing) = root.join(path).ancestors().find(|dir| dir.exists()) {
+        println!("cargo:rerun-if-changed={}", existing.display());
+    }
+}
+
+/// The example tests of `bin`, a test per case of its manifest.
+fn example_tests(root: &Path, bin: &Bin) -> String {
+    let path = format!("{}/{}", bin.examples_dir, bin.manifest);
+
+    match fs::read_to_string(root.join(&path)) {
+        Ok(s) => match s.parse::<manifest::Manifest>() {
+            Ok(manifest) => manifest.to_tests(),
+            Err(e) => manifest::failing_test(&format!("{path}: {e}")),
+        },
+        Err(e) => manifest::failing_test(&format!("could not read \"{path}\": {e}")),
+    }
+}
+
+fn main() {
+    let root = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let root = Path::new(&root);
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+
+    let bins = configured_path(root, "bins").unwrap_or_else(|| "src/bin".into());
+    let data = env::var("AOC_DATA_DIR")
+        .ok()
+        .filter(|dir| !dir.is_empty())
+        .or_else(|| configured_path(root, "data"))
+        .unwrap_or_else(|| "data".into());
+
+    println!("cargo:rerun-if-changed=aoc.toml");
+    println!("cargo:rerun-if-changed={bins}");
+    println!("cargo:rerun-if-env-changed=AOC_DATA_DIR");
+
+    let bin_dir = root.join(&bins);
+
+    // solutions are named `<day>.rs` or `<year>-<day>.rs`.
+    let is_number = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+
+    let mut days: Vec<Bin> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.extension()? != "rs" {
+                        return None;
+                    }
+
+                    let stem = path.file_stem()?.to_str()?;
+                    let (module, examples_dir, day) = match stem.split_once('-') {
+                        None => is_number(stem, 2)
+                            .then(|| (format!("day{stem}"), format!("{data}/examples"), stem)),
+                        Some((year, day)) => (is_number(year, 4) && is_number(day, 2)).then(|| {
+                            let examples_dir = format!("{data}/{year}/examples");
+                            (format!("y{year}_day{day}"), examples_dir, day)
+                        }),
+                    }?;
+
+                    Some(Bin {
+                        module,
+                        path: path.display().to_string(),
+                        crate_name: stem.replace('-', "_"),
+                        examples_dir,
+                        manifest: format!("{day}.manifest"),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort_by(|a, b| a.module.cmp(&b.module));
+
+    // the tests of a solution are run by its bin, so solutions are left out of the library's tests.
+    let mut out = String::new();
+
+    for bin in &days {
+        out += &format!(
+            "#[cfg(not(test))]\n#[path = {:?}]\n#[allow(dead_code)]\npub mod {};\n",
+            bin.path, bin.module
+        );
+    }
+
+    out +=
+        "\n#[cfg(not(test))]\npub static SOLUTIONS: &[crate::template::registry::Solution] = &[\n";
+    for bin in &days {
+        out += &format!("    {}::SOLUTION,\n", bin.module);
+    }
+    out += "];\n";
+    out += "\n#[cfg(test)]\npub static SOLUTIONS: &[crate::template::registry::Solution] = &[];\n";
+
+    fs::write(out_dir.join("solutions.rs"), out).unwrap();
+
+    // `example_tests!` includes `examples/<crate name>.rs`.
+    let examples_out = out_dir.join("examples");
+    fs::create_dir_all(&examples_out).unwrap();
+
+    let mut watched: Vec<&str> = vec![];
+
+    for bin in &days {
+        fs::write(
+            examples_out.join(format!("{}.rs", bin.crate_name)),
+            example_tests(root, bin),
+        )
+        .unwrap();
+
+        if !watched.contains(&bin.examples_dir.as_str()) {
+            watch(root, &bin.examples_dir);
+            watched.push(&bin.examples_dir);
+        }
+    }
+}
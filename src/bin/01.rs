@@ -1,10 +1,10 @@
-advent_of_code::solution!(1);
+advent_of_code::solution!(1, parse = parse);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let nums = input
-        .lines()
-        .map(|l| l.parse::<u32>().unwrap())
-        .collect::<Vec<_>>();
+pub fn parse(input: &str) -> Option<Vec<u32>> {
+    input.lines().map(|l| l.parse().ok()).collect()
+}
+
+pub fn part_one(nums: &[u32]) -> Option<u32> {
     for (i, a) in nums.iter().enumerate() {
         for b in &nums[i + 1..] {
             if a + b == 2020 {
@@ -15,11 +15,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let nums = input
-        .lines()
-        .map(|l| l.parse::<u32>().unwrap())
-        .collect::<Vec<_>>();
+pub fn part_two(nums: &[u32]) -> Option<u32> {
     for (i, a) in nums.iter().enumerate() {
         for (j, b) in nums[i + 1..].iter().enumerate() {
             for c in &nums[i + j + 1..] {
@@ -38,13 +34,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(514579));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(241861950));
     }
 }
@@ -1,8 +1,9 @@
 use std::{collections::HashSet, str::FromStr};
 
+use anyhow::Context;
 use strum::EnumString;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(8, parse = parse);
 
 #[derive(Clone, Copy, Debug, EnumString, PartialEq, Eq)]
 #[strum(serialize_all = "lowercase")]
@@ -13,7 +14,7 @@ enum Op {
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Instruction {
+pub struct Instruction {
     op: Op,
     val: isize,
 }
@@ -32,13 +33,28 @@ impl FromStr for Instruction {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (op, val) = s.split_once(' ').unwrap();
-        let val = val.parse()?;
-        let op = op.parse()?;
+        let (op, val) = s.split_once(' ').context("missing argument")?;
+        let val = val
+            .parse()
+            .with_context(|| format!("invalid argument `{val}`"))?;
+        let op = op
+            .parse()
+            .with_context(|| format!("unknown operation `{op}`"))?;
         Ok(Self { op, val })
     }
 }
 
+pub fn parse(input: &str) -> anyhow::Result<Vec<Instruction>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            l.parse()
+                .with_context(|| format!("invalid instruction `{l}` on line {}", i + 1))
+        })
+        .collect()
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 struct Computer {
     acc: isize,
@@ -86,28 +102,24 @@ impl Computer {
     }
 
     fn calc_pt2(&mut self, code: &[Instruction]) -> Option<i32> {
-        (0..)
-            .filter(|i| code.get(*i).unwrap().op != Op::Acc)
+        (0..code.len())
+            .filter(|i| code[*i].op != Op::Acc)
             .find_map(|i| self.calc_w_flipped(code, i))
     }
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
-    let code = input
-        .lines()
-        .map(|l| l.parse::<Instruction>().unwrap())
-        .collect::<Vec<_>>();
+pub fn part_one(code: &[Instruction]) -> anyhow::Result<i32> {
     let mut computer = Computer::default();
-    computer.calc(&code)
+    computer
+        .calc(code)
+        .context("program terminates without repeating an instruction")
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
-    let code = input
-        .lines()
-        .map(|l| l.parse::<Instruction>().unwrap())
-        .collect::<Vec<_>>();
+pub fn part_two(code: &[Instruction]) -> anyhow::Result<i32> {
     let mut computer = Computer::default();
-    computer.calc_pt2(&code)
+    computer
+        .calc_pt2(code)
+        .context("no flipped instruction makes the program terminate")
 }
 
 #[cfg(test)]
@@ -116,13 +128,24 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
+        assert_eq!(result.unwrap(), 5);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(8));
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
+        assert_eq!(result.unwrap(), 8);
+    }
+
+    #[test]
+    fn test_invalid_instruction() {
+        let error = parse("nop +0\nfoo +1\n").unwrap_err();
+        assert_eq!(
+            format!("{error:#}"),
+            "invalid instruction `foo +1` on line 2: unknown operation `foo`: Matching variant not found"
+        );
     }
 }
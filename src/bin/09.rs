@@ -1,13 +1,14 @@
 use std::cmp::Ordering;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, parse = parse);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let list = input
-        .lines()
-        .map(|l| l.parse::<u64>().unwrap())
-        .collect::<Vec<_>>();
-    const WINDOW_SIZE: usize = if cfg!(test) { 5 } else { 25 };
+const WINDOW_SIZE: usize = if cfg!(test) { 5 } else { 25 };
+
+pub fn parse(input: &str) -> Option<Vec<u64>> {
+    input.lines().map(|l| l.parse().ok()).collect()
+}
+
+pub fn part_one(list: &[u64]) -> Option<u64> {
     for window in list.windows(WINDOW_SIZE + 1) {
         if !window[..WINDOW_SIZE]
             .iter()
@@ -21,24 +22,8 @@ pub fn part_one(input: &str) -> Option<u64> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let list = input
-        .lines()
-        .map(|l| l.parse::<u64>().unwrap())
-        .collect::<Vec<_>>();
-    const WINDOW_SIZE: usize = if cfg!(test) { 5 } else { 25 };
-    let mut num = None;
-    for window in list.windows(WINDOW_SIZE + 1) {
-        if !window[..WINDOW_SIZE]
-            .iter()
-            .enumerate()
-            .flat_map(|(i, n1)| window[i + 1..WINDOW_SIZE].iter().map(move |n2| (n1, n2)))
-            .any(|(n1, n2)| n1 + n2 == window[WINDOW_SIZE])
-        {
-            num = Some(window[WINDOW_SIZE]);
-        }
-    }
-    let num = num.unwrap();
+pub fn part_two(list: &[u64]) -> Option<u64> {
+    let num = part_one(list)?;
     for start in 0..list.len() {
         let mut sum = 0;
         for (len, next) in list[start..].iter().enumerate() {
@@ -63,13 +48,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(127));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(62));
     }
 }
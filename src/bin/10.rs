@@ -1,16 +1,21 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(10, parse = parse);
 
-pub fn part_one(input: &str) -> Option<usize> {
+/// The sorted joltages of the adapters, including the outlet and the device.
+pub fn parse(input: &str) -> Option<Vec<u32>> {
     let mut nums = input
         .lines()
-        .map(|l| l.parse::<u32>().unwrap())
-        .chain(std::iter::once(0))
-        .collect::<Vec<_>>();
+        .map(|l| l.parse().ok())
+        .chain(std::iter::once(Some(0)))
+        .collect::<Option<Vec<u32>>>()?;
     nums.sort();
-    let max = nums.last().unwrap() + 3;
+    let max = nums.last()? + 3;
     nums.push(max);
+    Some(nums)
+}
+
+pub fn part_one(nums: &[u32]) -> Option<usize> {
     let num_ones = nums.windows(2).filter(|ns| ns[1] - ns[0] == 1).count();
     let num_threes = nums.windows(2).filter(|ns| ns[1] - ns[0] == 3).count();
     Some(num_ones * num_threes)
@@ -95,17 +100,9 @@ impl ChargerList {
     }
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let mut nums = input
-        .lines()
-        .map(|l| l.parse::<u32>().unwrap())
-        .chain(std::iter::once(0))
-        .collect::<Vec<_>>();
-    nums.sort();
-    let max = nums.last().unwrap() + 3;
-    nums.push(max);
+pub fn part_two(nums: &[u32]) -> Option<usize> {
     let mut list = ChargerList {
-        nums,
+        nums: nums.to_vec(),
         cache: HashMap::new(),
     };
     Some(list.num_ways_here_to_end(0))
@@ -117,29 +114,37 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_one_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
+        let result = part_one(
+            &parse(&advent_of_code::template::read_file_part(
+                "examples", DAY, 1,
+            ))
+            .unwrap(),
+        );
         assert_eq!(result, Some(220));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two_one() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
+        let result = part_two(
+            &parse(&advent_of_code::template::read_file_part(
+                "examples", DAY, 1,
+            ))
+            .unwrap(),
+        );
         assert_eq!(result, Some(19208));
     }
 }
@@ -4,19 +4,19 @@ use std::{
 };
 
 use advent_of_code::util::point::Pt;
-use anyhow::bail;
+use anyhow::{bail, Context};
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(11, parse = parse);
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
-enum Space {
+pub enum Space {
     Floor,
     Empty,
     Occupied,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
-struct Layout {
+pub struct Layout {
     spaces: Vec<Vec<Space>>,
     width: usize,
     height: usize,
@@ -27,9 +27,12 @@ impl FromStr for Layout {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let height = s.lines().count();
-        let width = s.lines().next().unwrap().len();
+        let width = s.lines().next().context("empty layout")?.len();
         let mut spaces = Vec::with_capacity(height);
-        for line in s.lines() {
+        for (y, line) in s.lines().enumerate() {
+            if line.len() != width {
+                bail!("Row {} has length {}, expected {width}", y + 1, line.len());
+            }
             let mut new_line = Vec::with_capacity(width);
             for c in line.chars() {
                 match c {
@@ -49,6 +52,10 @@ impl FromStr for Layout {
     }
 }
 
+pub fn parse(input: &str) -> anyhow::Result<Layout> {
+    input.parse()
+}
+
 const DIRS: [Pt<isize>; 8] = [
     Pt { x: -1, y: -1 },
     Pt { x: -1, y: 0 },
@@ -145,8 +152,8 @@ impl IndexMut<Pt<usize>> for Layout {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let mut layout = input.parse::<Layout>().unwrap();
+pub fn part_one(layout: &Layout) -> Option<usize> {
+    let mut layout = layout.clone();
     loop {
         let new_layout = layout.calc();
         if layout == new_layout {
@@ -156,8 +163,8 @@ pub fn part_one(input: &str) -> Option<usize> {
     }
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let mut layout = input.parse::<Layout>().unwrap();
+pub fn part_two(layout: &Layout) -> Option<usize> {
+    let mut layout = layout.clone();
     loop {
         let new_layout = layout.calc_v2();
         if layout == new_layout {
@@ -173,13 +180,21 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(37));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_two(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(26));
     }
+
+    #[test]
+    fn test_invalid_layout() {
+        let error = parse("L.L\nL?L\n").unwrap_err();
+        assert_eq!(error.to_string(), "Unknown char ?");
+    }
 }
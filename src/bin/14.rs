@@ -119,7 +119,7 @@ struct MaskIter<'a> {
 }
 
 impl BitMaskV2 {
-    fn mask_iter(&self, input: u64) -> MaskIter {
+    fn mask_iter(&self, input: u64) -> MaskIter<'_> {
         let input = input | self.ones;
         MaskIter {
             mask: self,
@@ -3,9 +3,9 @@ use std::{
     ops::RangeInclusive,
 };
 
-use anyhow::bail;
+use anyhow::{bail, Context};
 
-advent_of_code::solution!(16);
+advent_of_code::solution!(16, parse = parse);
 
 #[derive(Debug)]
 struct TicketReqs<'a> {
@@ -56,18 +56,51 @@ impl<'a> TicketReqs<'a> {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+/// The requirements, your ticket and the nearby tickets.
+#[derive(Debug)]
+pub struct Notes<'a> {
+    reqs: TicketReqs<'a>,
+    my_ticket: Vec<u32>,
+    nearby: Vec<Vec<u32>>,
+}
+
+fn parse_ticket(line: &str) -> anyhow::Result<Vec<u32>> {
+    line.split(',')
+        .map(|n| {
+            n.parse()
+                .with_context(|| format!("Invalid ticket value {n}"))
+        })
+        .collect()
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Notes<'_>> {
     let mut pts = input.split("\n\n");
-    let reqs = pts.next().unwrap();
-    let tickets = pts.nth(1).unwrap();
-    let reqs = TicketReqs::new(reqs).unwrap();
+    let reqs = TicketReqs::new(pts.next().context("Missing requirements")?)?;
+    let my_ticket = pts
+        .next()
+        .and_then(|pt| pt.lines().nth(1))
+        .context("Missing your ticket")?;
+    let nearby = pts
+        .next()
+        .context("Missing nearby tickets")?
+        .lines()
+        .skip(1)
+        .map(parse_ticket)
+        .collect::<anyhow::Result<_>>()?;
+    Ok(Notes {
+        reqs,
+        my_ticket: parse_ticket(my_ticket)?,
+        nearby,
+    })
+}
+
+pub fn part_one(notes: &Notes) -> Option<u32> {
     Some(
-        tickets
-            .lines()
-            .skip(1)
-            .flat_map(|l| l.split(','))
-            .map(|n| n.parse().unwrap())
-            .filter(|num| !reqs.is_valid(num))
+        notes
+            .nearby
+            .iter()
+            .flatten()
+            .filter(|num| !notes.reqs.is_valid(num))
             .sum(),
     )
 }
@@ -106,28 +139,17 @@ impl<'a> Candidates<'a> {
     }
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut pts = input.split("\n\n");
-    let reqs = pts.next().unwrap();
-    let my_ticket = pts
-        .next()
-        .unwrap()
-        .lines()
-        .nth(1)
-        .unwrap()
-        .split(',')
-        .map(|n| n.parse::<u32>().unwrap())
-        .collect::<Vec<_>>();
-    let reqs = TicketReqs::new(reqs).unwrap();
+pub fn part_two(notes: &Notes) -> Option<u64> {
+    let Notes {
+        reqs,
+        my_ticket,
+        nearby,
+    } = notes;
     let mut candidates = Candidates::new(reqs.candidates().collect(), my_ticket.len());
-    for line in pts.next().unwrap().lines().skip(1) {
-        for (index, cand) in line
-            .split(',')
-            .map(|n| n.parse::<u32>().unwrap())
-            .enumerate()
-        {
-            if reqs.is_valid(&cand) {
-                for candidate in reqs.is_not_valid_for(&cand) {
+    for ticket in nearby {
+        for (index, cand) in ticket.iter().enumerate() {
+            if reqs.is_valid(cand) {
+                for candidate in reqs.is_not_valid_for(cand) {
                     candidates.remove(candidate, index);
                 }
             }
@@ -150,7 +172,8 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result =
+            part_one(&parse(&advent_of_code::template::read_file("examples", DAY)).unwrap());
         assert_eq!(result, Some(71));
     }
 }
@@ -1,4 +1,11 @@
+// Lets solutions refer to this crate as `advent_of_code` when they are compiled as part of the library.
+extern crate self as advent_of_code;
+
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
 pub mod util;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
@@ -1,4 +1,7 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, extract, profile, read, scaffold, solve, status, time, verify,
+};
+use advent_of_code::template::report::OutputFormat;
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,7 +10,12 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::scaffold;
+    use advent_of_code::template::config::Config;
+    use advent_of_code::template::runner::{parse_samples, BenchConfig};
+    use advent_of_code::template::{
+        examples, parse_threshold, report::OutputFormat, Day, Executor, Limits, Year,
+    };
     use std::process;
 
     pub enum AppArguments {
@@ -17,43 +25,108 @@ mod args {
         Read {
             day: Day,
         },
+        Extract {
+            day: Day,
+            yes: bool,
+        },
         Scaffold {
             day: Day,
             download: bool,
+            template: String,
+            force: bool,
+            dry_run: bool,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            format: OutputFormat,
+            input: Option<String>,
+            watch: bool,
+            examples: bool,
+            slow: bool,
+            example: Option<examples::Selection>,
+            part: Option<u8>,
         },
         All {
             release: bool,
+            format: OutputFormat,
+            executor: Executor,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            memory: bool,
+            format: OutputFormat,
+            compare: bool,
+            threshold: Option<f64>,
+            bench: BenchConfig,
+            executor: Executor,
+        },
+        Verify {
+            day: Option<Day>,
+        },
+        Profile {
+            day: Day,
+            part: u8,
+        },
+        Status {
+            format: OutputFormat,
+        },
+        #[cfg(feature = "client")]
+        Leaderboard {
+            id: u64,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parses the command and the year it applies to.
+    /// The year defaults to the one in `aoc.toml` or `AOC_YEAR` and can be overridden with
+    /// `--year` for every command.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
+        // reports an invalid `aoc.toml` before any command starts.
+        Config::load()?;
+
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        // parse before any free-standing arguments, so that the year is not mistaken for a day.
+        let year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => Year::configured()
+                .ok_or("no year specified. Set `year` in aoc.toml, AOC_YEAR in .cargo/config.toml or pass --year.")?,
+        };
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                executor: parse_executor(&mut args)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let memory = args.contains("--memory");
+                let format = args.opt_value_from_str("--format")?.unwrap_or_default();
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_fn("--threshold", parse_threshold)?;
+                let bench = parse_bench(&mut args)?;
+                let executor = parse_executor(&mut args)?;
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    memory,
+                    format,
+                    compare,
+                    threshold,
+                    bench,
+                    executor,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -62,15 +135,56 @@ mod args {
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
+            Some("extract") => AppArguments::Extract {
+                day: args.free_from_str()?,
+                yes: args.contains("--yes"),
+            },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.into()),
+                force: args.contains("--force"),
+                dry_run: args.contains("--dry-run"),
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                input: args.opt_value_from_str("--input")?,
+                watch: args.contains("--watch"),
+                examples: args.contains("--examples"),
+                slow: args.contains("--slow"),
+                part: args.opt_value_from_fn("--part", examples::parse_part)?,
+                // parsed last, so that a bare `--example` does not take another flag as its value.
+                example: match args.opt_value_from_str("--example") {
+                    Err(pico_args::Error::OptionWithoutAValue(_)) => {
+                        args.contains("--example");
+                        Some(examples::Selection::All)
+                    }
+                    result => result?,
+                },
+            },
+            Some("verify") => {
+                // checking all days is the default, `--all` is accepted for symmetry with `time`.
+                args.contains("--all");
+                AppArguments::Verify {
+                    day: args.opt_free_from_str()?,
+                }
+            }
+            Some("profile") => AppArguments::Profile {
+                day: args.free_from_str()?,
+                part: args.opt_value_from_str("--part")?.unwrap_or(1),
+            },
+            Some("status") => AppArguments::Status {
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            },
+            #[cfg(feature = "client")]
+            Some("leaderboard") => AppArguments::Leaderboard {
+                id: args.free_from_str()?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -89,7 +203,69 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+
+    /// Parses `--bench-budget`, `--bench-warmup`, `--min-samples` and `--max-samples`, which
+    /// override the benchmark settings in `aoc.toml` for `time`.
+    fn parse_bench(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BenchConfig, Box<dyn std::error::Error>> {
+        let mut bench = Config::load()?.bench;
+
+        if let Some(budget) =
+            args.opt_value_from_fn("--bench-budget", BenchConfig::parse_duration)?
+        {
+            bench.budget = budget;
+        }
+        if let Some(warmup) =
+            args.opt_value_from_fn("--bench-warmup", BenchConfig::parse_duration)?
+        {
+            bench.warmup = warmup;
+        }
+        if let Some(min_samples) = args.opt_value_from_fn("--min-samples", parse_samples)? {
+            bench.min_samples = min_samples;
+        }
+        if let Some(max_samples) = args.opt_value_from_fn("--max-samples", parse_samples)? {
+            bench.max_samples = max_samples;
+        }
+
+        bench.validate()?;
+        Ok(bench)
+    }
+
+    /// Parses `--in-process`, `--jobs`, `--timeout` and `--memory-limit`, which control how `all`
+    /// and `time` run solutions.
+    fn parse_executor(args: &mut pico_args::Arguments) -> Result<Executor, pico_args::Error> {
+        let in_process = args.contains("--in-process");
+        // runs are serial by default so that benchmarks do not compete for resources.
+        let jobs = args
+            .opt_value_from_fn("--jobs", Executor::parse_jobs)?
+            .unwrap_or(1);
+        let limits = Limits {
+            timeout: args.opt_value_from_fn("--timeout", Limits::parse_timeout)?,
+            memory: args.opt_value_from_fn("--memory-limit", Limits::parse_memory)?,
+        };
+
+        if cfg!(not(unix)) && limits.memory.is_some() {
+            eprintln!("Warning: --memory-limit is only supported on unix.");
+        }
+
+        if !in_process {
+            return Ok(Executor::Process { jobs, limits });
+        }
+
+        if jobs > 1 {
+            eprintln!("Warning: --jobs is ignored when running solutions in-process.");
+        }
+
+        if limits != Limits::default() {
+            eprintln!(
+                "Warning: --timeout and --memory-limit are ignored when running solutions in-process."
+            );
+        }
+
+        Ok(Executor::InProcess)
     }
 }
 
@@ -99,30 +275,89 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day, download } => {
-                scaffold::handle(day);
-                if download {
-                    download::handle(day);
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                release,
+                format,
+                executor,
+            } => all::handle(year, release, format, executor),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                memory,
+                format,
+                compare,
+                threshold,
+                bench,
+                executor,
+            } => time::handle(
+                year, day, all, store, memory, format, compare, threshold, bench, executor,
+            ),
+            AppArguments::Verify { day } => verify::handle(year, day),
+            AppArguments::Profile { day, part } => profile::handle(year, day, part),
+            AppArguments::Status { format } => status::handle(year, format),
+            #[cfg(feature = "client")]
+            AppArguments::Leaderboard { id } => {
+                advent_of_code::template::commands::leaderboard::handle(year, id)
+            }
+            AppArguments::Download { day } => download::handle(year, day),
+            AppArguments::Read { day } => read::handle(year, day),
+            AppArguments::Extract { day, yes } => extract::handle(year, day, yes),
+            AppArguments::Scaffold {
+                day,
+                download,
+                template,
+                force,
+                dry_run,
+            } => {
+                // downloading first fills in the puzzle title and example answers.
+                if download && dry_run {
+                    eprintln!("Warning: --download is ignored with --dry-run.");
+                } else if download {
+                    download::handle(year, day);
                 }
+                scaffold::handle(year, day, &template, force, dry_run);
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                format,
+                input,
+                watch,
+                examples,
+                slow,
+                example,
+                part,
+            } => {
+                if part.is_some() && example.is_none() {
+                    eprintln!("Warning: --part is only used with --example.");
+                }
+
+                if let Some(selection) = example {
+                    solve::example_files(year, day, selection, part);
+                } else if examples {
+                    solve::examples(year, day, slow);
+                } else if watch {
+                    if submit.is_some() || format != OutputFormat::Human || input.is_some() {
+                        eprintln!(
+                            "Warning: --submit, --format and --input are ignored in watch mode."
+                        );
+                    }
+                    solve::watch(year, day, release, dhat);
+                } else {
+                    solve::handle(year, day, release, dhat, submit, format, input);
+                }
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day);
-                        download::handle(day);
-                        read::handle(day)
+                        download::handle(year, day);
+                        scaffold::handle(year, day, scaffold::DEFAULT_TEMPLATE, false, false);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,195 @@
+/// Module that stores accepted answers, so that solutions can be checked against them later.
+use std::{fs, io, path::Path};
+
+use crate::template::{Day, Scope};
+
+/// Accepted answers of a single day.
+///
+/// Stored in `data/answers/<day>.txt` (`data/<year>/answers/<day>.txt` for year-scoped solutions),
+/// with the answer to part one on the first line and the answer to part two on the second line.
+/// An empty line means that the answer is not known yet. Answers that span several lines, like
+/// letters drawn by a grid, are stored on one line with their line breaks escaped as `\n`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    /// Reads the answers for `day`. If the file is not present, returns empty answers.
+    pub fn read(scope: Scope, day: Day) -> Self {
+        fs::read_to_string(get_path(scope, day))
+            .map(|s| Answers::from(s.as_str()))
+            .unwrap_or_default()
+    }
+
+    pub fn store(&self, scope: Scope, day: Day) -> io::Result<()> {
+        let path = get_path(scope, day);
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        let answer = Some(answer.to_string());
+        match part {
+            1 => self.part_1 = answer,
+            2 => self.part_2 = answer,
+            _ => {}
+        }
+    }
+
+    /// Compares `actual`, the result of running a part, against the accepted answer.
+    pub fn verify(&self, part: u8, actual: Option<&str>) -> Verdict {
+        match (self.get(part), actual) {
+            (None, _) => Verdict::Missing,
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), actual) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual: actual.map(ToString::to_string),
+            },
+        }
+    }
+}
+
+/// Record `answer` as the accepted answer of a part.
+pub fn record(scope: Scope, day: Day, part: u8, answer: &str) -> io::Result<()> {
+    let mut answers = Answers::read(scope, day);
+    answers.set(part, answer);
+    answers.store(scope, day)
+}
+
+#[must_use]
+pub fn get_path(scope: Scope, day: Day) -> String {
+    scope.data_path("answers", &format!("{day}.txt"))
+}
+
+impl From<&str> for Answers {
+    fn from(s: &str) -> Self {
+        let mut lines = s.lines().map(|line| {
+            let line = line.trim();
+            (!line.is_empty()).then(|| unescape(line))
+        });
+
+        Answers {
+            part_1: lines.next().flatten(),
+            part_2: lines.next().flatten(),
+        }
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", escape(self.part_1.as_deref().unwrap_or_default()))?;
+        writeln!(f, "{}", escape(self.part_2.as_deref().unwrap_or_default()))
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(line: &str) -> String {
+    let mut answer = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let escaped = match (c, chars.peek()) {
+            ('\\', Some('n')) => '\n',
+            ('\\', Some('\\')) => '\\',
+            _ => {
+                answer.push(c);
+                continue;
+            }
+        };
+        answer.push(escaped);
+        chars.next();
+    }
+
+    answer
+}
+
+/// Result of checking a part against its accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// There is no accepted answer to check against.
+    Missing,
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::from("42\n1337\n");
+        assert_eq!(answers.get(1), Some("42"));
+        assert_eq!(answers.get(2), Some("1337"));
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = Answers::from("\n1337\n");
+        assert_eq!(answers.part_1, None);
+        assert_eq!(answers.get(2), Some("1337"));
+
+        let answers = Answers::from("42");
+        assert_eq!(answers.get(1), Some("42"));
+        assert_eq!(answers.part_2, None);
+    }
+
+    #[test]
+    fn serializes_answers() {
+        let answers = Answers {
+            part_1: None,
+            part_2: Some("1337".into()),
+        };
+        assert_eq!(answers.to_string(), "\n1337\n");
+        assert_eq!(Answers::from(answers.to_string().as_str()), answers);
+    }
+
+    #[test]
+    fn escapes_multiline_answers() {
+        let answers = Answers {
+            part_1: Some("#..#\n####\n#..#".into()),
+            part_2: Some("C:\\n".into()),
+        };
+        assert_eq!(answers.to_string(), "#..#\\n####\\n#..#\nC:\\\\n\n");
+        assert_eq!(Answers::from(answers.to_string().as_str()), answers);
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = Answers::from("42\n");
+        assert_eq!(answers.verify(1, Some("42")), Verdict::Pass);
+        assert_eq!(
+            answers.verify(1, Some("41")),
+            Verdict::Fail {
+                expected: "42".into(),
+                actual: Some("41".into())
+            }
+        );
+        assert_eq!(
+            answers.verify(1, None),
+            Verdict::Fail {
+                expected: "42".into(),
+                actual: None
+            }
+        );
+        assert_eq!(answers.verify(2, Some("42")), Verdict::Missing);
+    }
+}
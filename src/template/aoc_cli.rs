@@ -1,16 +1,22 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs, io,
+    path::Path,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use strum::{Display, EnumString};
+
+use crate::template::{Day, Scope, Year};
 
 #[derive(Debug)]
 pub enum AocCommandError {
     CommandNotFound,
     CommandNotCallable,
     BadExitStatus(Output),
+    /// The directories for the downloaded files could not be created.
+    Io(io::Error),
 }
 
 impl Display for AocCommandError {
@@ -21,10 +27,61 @@ impl Display for AocCommandError {
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
+            AocCommandError::Io(e) => write!(f, "could not create data directories: {e}"),
         }
     }
 }
 
+/// How Advent of Code responded to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum SubmissionOutcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, the answer was not checked.
+    RateLimited,
+    /// The part was already solved, the answer was not checked.
+    AlreadyCompleted,
+    /// The response could not be understood.
+    Unknown,
+}
+
+impl SubmissionOutcome {
+    /// Parses the response that aoc-cli prints after submitting an answer.
+    pub fn parse(response: &str) -> Self {
+        // aoc-cli wraps the response to the terminal width.
+        let response = response.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if response.contains("That's the right answer") {
+            SubmissionOutcome::Correct
+        } else if response.contains("That's not the right answer") {
+            if response.contains("your answer is too high") {
+                SubmissionOutcome::TooHigh
+            } else if response.contains("your answer is too low") {
+                SubmissionOutcome::TooLow
+            } else {
+                SubmissionOutcome::Incorrect
+            }
+        } else if response.contains("You gave an answer too recently") {
+            SubmissionOutcome::RateLimited
+        } else if response.contains("Did you already complete it") {
+            SubmissionOutcome::AlreadyCompleted
+        } else {
+            SubmissionOutcome::Unknown
+        }
+    }
+
+    /// Whether the answer was checked and rejected.
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            SubmissionOutcome::Incorrect | SubmissionOutcome::TooHigh | SubmissionOutcome::TooLow
+        )
+    }
+}
+
 pub fn check() -> Result<(), AocCommandError> {
     Command::new("aoc")
         .arg("-V")
@@ -33,8 +90,8 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(year: Year, day: Day) -> Result<Output, AocCommandError> {
+    let puzzle_path = Scope::resolve(year, day).puzzle_path(day);
 
     let args = build_args(
         "read",
@@ -43,15 +100,24 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
+        year,
         day,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(year: Year, day: Day) -> Result<Output, AocCommandError> {
+    let scope = Scope::resolve(year, day);
+    let input_path = scope.input_path(day);
+    let puzzle_path = scope.puzzle_path(day);
+
+    // aoc-cli does not create the directories of year-scoped paths.
+    for path in [&input_path, &puzzle_path] {
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir).map_err(AocCommandError::Io)?;
+        }
+    }
 
     let args = build_args(
         "download",
@@ -62,6 +128,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
+        year,
         day,
     );
 
@@ -72,36 +139,28 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(
+    year: Year,
+    day: Day,
+    part: u8,
+    result: &str,
+) -> Result<SubmissionOutcome, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], year, day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
-
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+    let output = call_aoc_cli_captured(&args)?;
+    Ok(SubmissionOutcome::parse(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
-}
-
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], year: Year, day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
+    cmd_args.push("--year".into());
+    cmd_args.push(year.to_string());
 
     cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
 
@@ -123,3 +182,70 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/// Like [`call_aoc_cli`], but captures stdout so that it can be inspected. It is still echoed.
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCommandError> {
+    let output = Command::new("aoc")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::SubmissionOutcome;
+
+    #[test]
+    fn parses_submission_responses() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer to saving your vacation.",
+                SubmissionOutcome::Correct,
+            ),
+            (
+                "That's not the right answer. If you're stuck, make sure you're using the full input data.",
+                SubmissionOutcome::Incorrect,
+            ),
+            (
+                "That's not the right answer; your answer is too high. If you're stuck, ...",
+                SubmissionOutcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is\ntoo low. Please wait one minute.",
+                SubmissionOutcome::TooLow,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 34s left to wait.",
+                SubmissionOutcome::RateLimited,
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                SubmissionOutcome::AlreadyCompleted,
+            ),
+            ("", SubmissionOutcome::Unknown),
+        ];
+
+        for (response, expected) in cases {
+            assert_eq!(SubmissionOutcome::parse(response), expected, "{response}");
+        }
+    }
+
+    #[test]
+    fn roundtrips_outcome_names() {
+        assert_eq!(SubmissionOutcome::TooHigh.to_string(), "too_high");
+        assert_eq!(
+            "rate_limited".parse::<SubmissionOutcome>(),
+            Ok(SubmissionOutcome::RateLimited)
+        );
+    }
+}
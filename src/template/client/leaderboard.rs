@@ -0,0 +1,148 @@
+/// Private leaderboards, as served by `/<year>/leaderboard/private/view/<id>.json`.
+use std::collections::HashMap;
+
+use tinyjson::JsonValue;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// Anonymous users do not have a name.
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    /// Members, ordered by their local score.
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// Renders the leaderboard as a ranked table.
+    pub fn format_table(&self) -> String {
+        let mut lines = vec![format!(
+            "{:>4} {:>6} {:>6}  {}",
+            "Rank", "Score", "Stars", "Name"
+        )];
+
+        for (i, member) in self.members.iter().enumerate() {
+            lines.push(format!(
+                "{:>4} {:>6} {:>6}  {}",
+                i + 1,
+                member.local_score,
+                member.stars,
+                member.display_name()
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl TryFrom<&str> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = value.parse::<JsonValue>().or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected leaderboard to be an object.")?;
+
+        let event = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .ok_or("expected `leaderboard.event` to be a string.")?
+            .clone();
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `leaderboard.members` to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+
+        Ok(Leaderboard { event, members })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected member to be an object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|n| *n as u64)
+                .ok_or(format!("expected `member.{key}` to be a number."))
+        };
+
+        Ok(Member {
+            id: number("id")?,
+            name: json.get("name").and_then(|v| v.get::<String>()).cloned(),
+            local_score: number("local_score")?,
+            stars: number("stars")?,
+        })
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Leaderboard;
+
+    const LEADERBOARD: &str = r#"{
+        "event": "2020",
+        "owner_id": 1,
+        "members": {
+            "1": { "id": 1, "name": "alice", "local_score": 20, "stars": 4, "global_score": 0 },
+            "2": { "id": 2, "name": null, "local_score": 35, "stars": 6, "global_score": 0 },
+            "3": { "id": 3, "name": "bob", "local_score": 20, "stars": 5, "global_score": 0 }
+        }
+    }"#;
+
+    #[test]
+    fn parses_leaderboard() {
+        let leaderboard = Leaderboard::try_from(LEADERBOARD).unwrap();
+        assert_eq!(leaderboard.event, "2020");
+
+        let ids: Vec<u64> = leaderboard.members.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![2, 3, 1]);
+        assert_eq!(leaderboard.members[0].display_name(), "(anonymous user #2)");
+    }
+
+    #[test]
+    fn formats_leaderboard() {
+        let table = Leaderboard::try_from(LEADERBOARD).unwrap().format_table();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[2], "   2     20      5  bob");
+    }
+
+    #[test]
+    fn fails_for_malformed_leaderboard() {
+        assert!(Leaderboard::try_from(r#"{ "event": "2020" }"#).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+//! Converts the HTML served by adventofcode.com to Markdown and plain text.
+//!
+//! This only understands the handful of tags used in puzzle descriptions and is not a general
+//! purpose HTML parser.
+
+enum Token<'a> {
+    Open { name: String, attrs: &'a str },
+    Close { name: String },
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let Some(end) = rest[start..].find('>') else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close {
+                name: name.trim().to_lowercase(),
+            });
+        } else if !tag.starts_with('!') {
+            let tag = tag.trim_end_matches('/');
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open {
+                name: name.to_lowercase(),
+                attrs,
+            });
+        }
+    }
+
+    tokens
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn attr<'a>(attrs: &'a str, key: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{key}=\""))? + key.len() + 2;
+    let len = attrs[start..].find('"')?;
+    Some(&attrs[start..start + len])
+}
+
+/// Returns the inner HTML of every `<article>` element.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let body_start = start + open_end + 1;
+        let Some(len) = rest[body_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[body_start..body_start + len]);
+        rest = &rest[body_start + len..];
+    }
+
+    articles
+}
+
+/// Converts a puzzle description to Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) if in_pre => out += &decode_entities(text),
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                if text.starts_with(char::is_whitespace) && !out.ends_with([' ', '\n']) {
+                    out.push(' ');
+                }
+                out += &collapsed;
+                if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                    out.push(' ');
+                }
+            }
+            Token::Open { name, attrs } => match name.as_str() {
+                "h2" => out += "## ",
+                "em" => out += "*",
+                "code" if !in_pre => out += "`",
+                "pre" => {
+                    in_pre = true;
+                    out += "```\n";
+                }
+                "li" => out += "- ",
+                "br" => out += "\n",
+                "a" => {
+                    links.push(attr(attrs, "href").unwrap_or_default().to_string());
+                    out += "[";
+                }
+                _ => {}
+            },
+            Token::Close { name } => match name.as_str() {
+                "h2" | "p" => out = out.trim_end_matches(' ').to_string() + "\n\n",
+                "em" => out += "*",
+                "code" if !in_pre => out += "`",
+                "pre" => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out += "```\n\n";
+                }
+                "li" => out = out.trim_end_matches(' ').to_string() + "\n",
+                "ul" => out += "\n",
+                "a" => out += &format!("]({})", links.pop().unwrap_or_default()),
+                _ => {}
+            },
+        }
+    }
+
+    let mut lines: Vec<&str> = vec![];
+    for line in out.lines() {
+        // collapse runs of blank lines.
+        if line.trim().is_empty() && lines.last().is_none_or(|l| l.trim().is_empty()) {
+            continue;
+        }
+        lines.push(line.trim_end());
+    }
+
+    lines.join("\n").trim().to_string() + "\n"
+}
+
+/// Strips all tags from `html`, keeping only its text.
+pub fn to_text(html: &str) -> String {
+    let text: String = tokenize(html)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(decode_entities(text)),
+            _ => None,
+        })
+        .collect();
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{articles, to_markdown, to_text};
+
+    const PUZZLE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Report Repair ---</h2><p>After saving Christmas <a href="/2015" target="_blank">five years in a row</a>, you've decided to take a vacation.</p>
+<p>Specifically, they need you to <em>find the two entries that sum to <code>2020</code></em> and then multiply those two numbers together.</p>
+<ul>
+<li><code>1721</code> &amp; <code>299</code></li>
+<li>Their product is <code>514579</code>.</li>
+</ul>
+<pre><code>1721
+979
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find <em>three</em> entries.</p></article>
+</main>"#;
+
+    #[test]
+    fn extracts_articles() {
+        let articles = articles(PUZZLE);
+        assert_eq!(articles.len(), 2);
+        assert!(articles[1].starts_with("<h2 id=\"part2\">"));
+    }
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        let markdown = to_markdown(&articles(PUZZLE).join("\n"));
+        assert_eq!(
+            markdown,
+            "## --- Day 1: Report Repair ---\n\n\
+             After saving Christmas [five years in a row](/2015), you've decided to take a vacation.\n\n\
+             Specifically, they need you to *find the two entries that sum to `2020`* and then multiply those two numbers together.\n\n\
+             - `1721` & `299`\n\
+             - Their product is `514579`.\n\n\
+             ```\n1721\n979\n```\n\n\
+             ## --- Part Two ---\n\n\
+             Find *three* entries.\n"
+        );
+    }
+
+    #[test]
+    fn converts_response_to_text() {
+        let html = "<article><p>That's not the right answer; your answer is too high.  \
+                    <a href=\"/2020/day/1\">[Return to Day 1]</a></p></article>";
+        assert_eq!(
+            to_text(articles(html)[0]),
+            "That's not the right answer; your answer is too high. [Return to Day 1]"
+        );
+    }
+}
@@ -0,0 +1,389 @@
+/// Built-in client for adventofcode.com, an alternative to shelling out to aoc-cli.
+///
+/// Requests are authenticated with the session cookie of a logged-in browser. It is read from the
+/// `AOC_SESSION` environment variable or from the files aoc-cli uses, `~/.adventofcode.session`
+/// and `~/.config/adventofcode.session`.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use ureq::Agent;
+
+use crate::template::aoc_cli::SubmissionOutcome;
+use crate::template::{Day, Scope, Year};
+
+pub use leaderboard::{Leaderboard, Member};
+
+mod leaderboard;
+mod markdown;
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    SessionNotFound,
+    /// The request could not be sent or its response could not be read.
+    Http(ureq::Error),
+    BadStatus(u16),
+    BadResponse(String),
+    IO(std::io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or write it to ~/.adventofcode.session."
+            ),
+            ClientError::Http(e) => write!(f, "request failed: {e}"),
+            ClientError::BadStatus(400) => {
+                write!(f, "request was rejected. Is your session cookie valid?")
+            }
+            ClientError::BadStatus(404) => {
+                write!(f, "not found. Is the puzzle unlocked yet?")
+            }
+            ClientError::BadStatus(status) => write!(f, "server responded with status {status}."),
+            ClientError::BadResponse(e) => write!(f, "unexpected response: {e}"),
+            ClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        ClientError::Http(e)
+    }
+}
+
+impl From<std::io::Error> for ClientError {
+    fn from(e: std::io::Error) -> Self {
+        ClientError::IO(e)
+    }
+}
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl Client {
+    pub fn new(session: &str, year: Year) -> Self {
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: BASE_URL.into(),
+            session: session.trim().into(),
+            year,
+        }
+    }
+
+    /// Sends requests to `base_url` instead of adventofcode.com.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').into();
+        self
+    }
+
+    /// Creates a client for `year`, using the session cookie of the current user.
+    pub fn from_env(year: Year) -> Result<Self, ClientError> {
+        Ok(Self::new(&read_session()?, year))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn get(&self, url: &str) -> Result<String, ClientError> {
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call()?;
+
+        match response.status().as_u16() {
+            200 => Ok(response.body_mut().read_to_string()?),
+            status => Err(ClientError::BadStatus(status)),
+        }
+    }
+
+    pub fn get_input(&self, day: Day) -> Result<String, ClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetches the puzzle description and converts it to Markdown.
+    /// The description of part two is only included once part one has been solved.
+    pub fn get_puzzle(&self, day: Day) -> Result<String, ClientError> {
+        let html = self.get(&self.day_url(day))?;
+        let articles = markdown::articles(&html);
+
+        if articles.is_empty() {
+            return Err(ClientError::BadResponse(
+                "no puzzle description found.".into(),
+            ));
+        }
+
+        Ok(markdown::to_markdown(&articles.join("\n")))
+    }
+
+    /// Submits `answer` for `part`. Returns the outcome and the message shown by the server.
+    pub fn submit(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<(SubmissionOutcome, String), ClientError> {
+        let mut response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])?;
+
+        if response.status().as_u16() != 200 {
+            return Err(ClientError::BadStatus(response.status().as_u16()));
+        }
+
+        let html = response.body_mut().read_to_string()?;
+        let message = markdown::articles(&html)
+            .first()
+            .map(|article| markdown::to_text(article))
+            .ok_or(ClientError::BadResponse("no message found.".into()))?;
+
+        Ok((SubmissionOutcome::parse(&message), message))
+    }
+
+    pub fn get_leaderboard(&self, id: u64) -> Result<Leaderboard, ClientError> {
+        let json = self.get(&format!(
+            "{}/{}/leaderboard/private/view/{id}.json",
+            self.base_url, self.year
+        ))?;
+
+        Leaderboard::try_from(json.as_str()).map_err(ClientError::BadResponse)
+    }
+}
+
+/// Reads the session cookie from `AOC_SESSION` or from one of the session files used by aoc-cli.
+fn read_session() -> Result<String, ClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session);
+        }
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .ok_or(ClientError::SessionNotFound)?;
+
+    let config_dir =
+        env::var_os("XDG_CONFIG_HOME").map_or_else(|| home.join(".config"), PathBuf::from);
+
+    [
+        home.join(".adventofcode.session"),
+        config_dir.join("adventofcode.session"),
+    ]
+    .iter()
+    .find_map(|path| fs::read_to_string(path).ok())
+    .filter(|session| !session.trim().is_empty())
+    .ok_or(ClientError::SessionNotFound)
+}
+
+/// Writes `contents` to `path`, creating missing data folders of year-scoped solutions.
+fn write_file(path: &str, contents: &str) -> Result<(), ClientError> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    Ok(fs::write(path, contents)?)
+}
+
+/// Downloads input and puzzle description of `day`, like `aoc_cli::download`.
+pub fn download(year: Year, day: Day) -> Result<(), ClientError> {
+    let client = Client::from_env(year)?;
+
+    let scope = Scope::resolve(year, day);
+    let input_path = scope.input_path(day);
+    let puzzle_path = scope.puzzle_path(day);
+
+    write_file(&input_path, &client.get_input(day)?)?;
+    write_file(&puzzle_path, &client.get_puzzle(day)?)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Fetches the puzzle description of `day`, stores and prints it, like `aoc_cli::read`.
+pub fn read(year: Year, day: Day) -> Result<(), ClientError> {
+    let puzzle = Client::from_env(year)?.get_puzzle(day)?;
+    write_file(&Scope::resolve(year, day).puzzle_path(day), &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+/// Submits `answer` and prints the server's response, like `aoc_cli::submit`.
+pub fn submit(
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: &str,
+) -> Result<SubmissionOutcome, ClientError> {
+    let (outcome, message) = Client::from_env(year)?.submit(day, part, answer)?;
+    println!("{message}");
+    Ok(outcome)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{Client, ClientError};
+    use crate::template::aoc_cli::SubmissionOutcome;
+    use crate::{day, year};
+
+    /// A request received by [`serve`].
+    struct Request {
+        head: String,
+        body: String,
+    }
+
+    /// Serves a single request on a local port with `status` and `body`.
+    /// Returns the base URL of the server and a receiver for the request it got.
+    fn serve(status: u16, body: &'static str) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head += &line;
+            }
+
+            let content_length = head
+                .lines()
+                .find_map(|l| {
+                    let (key, value) = l.split_once(':')?;
+                    key.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().ok())?
+                })
+                .unwrap_or(0);
+
+            let mut body_bytes = vec![0; content_length];
+            reader.read_exact(&mut body_bytes).unwrap();
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} X\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            tx.send(Request {
+                head,
+                body: String::from_utf8(body_bytes).unwrap(),
+            })
+            .unwrap();
+        });
+
+        (url, rx)
+    }
+
+    fn client(url: &str) -> Client {
+        Client::new("secret\n", year!(2020)).with_base_url(url)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (url, rx) = serve(200, "1721\n979\n");
+        let input = client(&url).get_input(day!(1)).unwrap();
+        assert_eq!(input, "1721\n979\n");
+
+        let request = rx.recv().unwrap();
+        assert!(request.head.starts_with("GET /2020/day/1/input "));
+        assert!(request
+            .head
+            .lines()
+            .any(|l| l.eq_ignore_ascii_case("cookie: session=secret")));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (url, _rx) = serve(400, "Puzzle inputs differ by user.");
+        let result = client(&url).get_input(day!(1));
+        assert!(matches!(result, Err(ClientError::BadStatus(400))));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (url, rx) = serve(
+            200,
+            "<html><main><article class=\"day-desc\"><h2>--- Day 8: Test ---</h2>\
+             <p>Hello <em>world</em>.</p></article></main></html>",
+        );
+        let puzzle = client(&url).get_puzzle(day!(8)).unwrap();
+        assert_eq!(puzzle, "## --- Day 8: Test ---\n\nHello *world*.\n");
+        assert!(rx.recv().unwrap().head.starts_with("GET /2020/day/8 "));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, rx) = serve(
+            200,
+            "<main><article><p>That's not the right answer; your answer is too low. \
+             <a href=\"/2020/day/3\">[Return to Day 3]</a></p></article></main>",
+        );
+        let (outcome, message) = client(&url).submit(day!(3), 2, "42").unwrap();
+        assert_eq!(outcome, SubmissionOutcome::TooLow);
+        assert!(message.starts_with("That's not the right answer"));
+
+        let request = rx.recv().unwrap();
+        assert!(request.head.starts_with("POST /2020/day/3/answer "));
+        assert_eq!(request.body, "level=2&answer=42");
+    }
+
+    #[test]
+    fn fetches_leaderboard() {
+        let (url, rx) = serve(
+            200,
+            r#"{ "event": "2020", "members": { "7": { "id": 7, "name": "alice", "local_score": 3, "stars": 2 } } }"#,
+        );
+        let leaderboard = client(&url).get_leaderboard(1234).unwrap();
+        assert_eq!(leaderboard.members[0].name.as_deref(), Some("alice"));
+        assert!(rx
+            .recv()
+            .unwrap()
+            .head
+            .starts_with("GET /2020/leaderboard/private/view/1234.json "));
+    }
+}
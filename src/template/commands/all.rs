@@ -1,5 +1,17 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{
+    all_days, report::OutputFormat, run_multi::run_multi, runner::RunOptions, Executor, Year,
+};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(year: Year, is_release: bool, format: OutputFormat, executor: Executor) {
+    run_multi(
+        year,
+        &all_days().collect(),
+        is_release,
+        false,
+        &RunOptions {
+            format,
+            ..RunOptions::default()
+        },
+        executor,
+    );
 }
@@ -0,0 +1,240 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process,
+};
+
+use crate::template::examples::{self, Example, ExampleFile, Manifest};
+use crate::template::puzzle::{ExampleBlock, Puzzle};
+use crate::template::{Day, Scope, Year, ANSI_BOLD, ANSI_RESET};
+
+/// Number of lines of an example shown in the proposal.
+const PREVIEW_LINES: usize = 5;
+
+/// What happens to a proposed example file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Action {
+    Create,
+    /// The file exists, but is empty, e.g. because it was scaffolded.
+    Fill,
+    Unchanged,
+    /// The file exists with other contents and is left alone.
+    Skip,
+}
+
+impl Action {
+    fn for_file(path: &str, input: &str) -> Self {
+        match fs::read_to_string(path) {
+            Err(_) => Action::Create,
+            Ok(s) if s.trim().is_empty() => Action::Fill,
+            Ok(s) if s == input => Action::Unchanged,
+            Ok(_) => Action::Skip,
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Action::Create => "new",
+            Action::Fill => "fills empty file",
+            Action::Unchanged => "unchanged",
+            Action::Skip => "exists, skipped",
+        }
+    }
+}
+
+/// An example file that is proposed to be written.
+struct Proposal {
+    file: String,
+    path: String,
+    block: ExampleBlock,
+    action: Action,
+}
+
+/// Proposes example files for the example blocks of a puzzle description: the first block becomes
+/// `<day>.txt`, later blocks with an answer become `<day>-<n>.txt`.
+fn propose(scope: Scope, day: Day, blocks: Vec<ExampleBlock>) -> Vec<Proposal> {
+    blocks
+        .into_iter()
+        .enumerate()
+        .filter(|(i, block)| *i == 0 || block.answers.iter().any(Option::is_some))
+        .enumerate()
+        .map(|(n, (_, block))| {
+            let file = match n {
+                0 => ExampleFile::Main,
+                #[allow(clippy::cast_possible_truncation)]
+                n => ExampleFile::Numbered(n as u8),
+            }
+            .name(day);
+            let path = scope.data_path("examples", &file);
+            let action = Action::for_file(&path, &block.input);
+
+            Proposal {
+                file,
+                path,
+                block,
+                action,
+            }
+        })
+        .collect()
+}
+
+/// Manifest cases for the expected answers of written examples that the manifest does not cover yet.
+fn new_cases(proposals: &[Proposal], manifest: &Manifest) -> Vec<Example> {
+    proposals
+        .iter()
+        .filter(|proposal| proposal.action != Action::Skip)
+        .flat_map(|proposal| {
+            (1..=2)
+                .zip(&proposal.block.answers)
+                .filter_map(|(part, answer)| {
+                    Some(Example {
+                        file: proposal.file.clone(),
+                        part,
+                        expected: answer.clone()?,
+                        slow: false,
+                    })
+                })
+        })
+        .filter(|case| {
+            !manifest
+                .examples
+                .iter()
+                .any(|e| e.file == case.file && e.part == case.part)
+        })
+        .collect()
+}
+
+fn print_proposal(proposal: &Proposal) {
+    let answers: Vec<String> = (1..=2)
+        .zip(&proposal.block.answers)
+        .filter_map(|(part, answer)| Some(format!("part {part}: {}", answer.as_ref()?)))
+        .collect();
+    let answers = if answers.is_empty() {
+        "no answers found".to_string()
+    } else {
+        answers.join(", ")
+    };
+
+    println!(
+        "{ANSI_BOLD}{}{ANSI_RESET} ({}, {answers})",
+        proposal.path,
+        proposal.action.describe()
+    );
+
+    let lines: Vec<&str> = proposal.block.input.lines().collect();
+    for line in lines.iter().take(PREVIEW_LINES) {
+        println!("    {line}");
+    }
+    if lines.len() > PREVIEW_LINES {
+        println!("    … ({} more lines)", lines.len() - PREVIEW_LINES);
+    }
+    println!();
+}
+
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).is_ok()
+        && matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+fn write_manifest(path: &str, cases: &[Example]) -> Result<(), io::Error> {
+    let exists = Path::new(path).exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if !exists {
+        writeln!(file, "# file part expected [slow]")?;
+    }
+    for case in cases {
+        writeln!(file, "{case}")?;
+    }
+
+    Ok(())
+}
+
+/// Proposes example files and manifest cases from the downloaded puzzle description of a day,
+/// and writes them once confirmed. `yes` skips the confirmation.
+pub fn handle(year: Year, day: Day, yes: bool) {
+    let scope = Scope::resolve(year, day);
+
+    let Some(puzzle) = Puzzle::read(scope, day) else {
+        eprintln!(
+            "Could not read \"{}\". Download the puzzle description first, e.g. via `cargo read {day}`.",
+            scope.puzzle_path(day)
+        );
+        process::exit(1);
+    };
+
+    let proposals = propose(scope, day, puzzle.example_blocks());
+    if proposals.is_empty() {
+        eprintln!("No examples found in \"{}\".", scope.puzzle_path(day));
+        process::exit(1);
+    }
+
+    let manifest_path = examples::get_path(scope, day);
+    let manifest = Manifest::read(scope, day).unwrap_or_default();
+    let cases = new_cases(&proposals, &manifest);
+
+    proposals.iter().for_each(print_proposal);
+
+    if !cases.is_empty() {
+        println!("{ANSI_BOLD}{manifest_path}{ANSI_RESET}");
+        for case in &cases {
+            println!("    {case}");
+        }
+        println!();
+    }
+
+    let to_write: Vec<&Proposal> = proposals
+        .iter()
+        .filter(|p| matches!(p.action, Action::Create | Action::Fill))
+        .collect();
+
+    if to_write.is_empty() && cases.is_empty() {
+        println!("Nothing to write.");
+        return;
+    }
+
+    let question = format!(
+        "Write {} example file(s) and {} manifest case(s)?",
+        to_write.len(),
+        cases.len()
+    );
+    if !yes && !confirm(&question) {
+        println!("Nothing was written.");
+        return;
+    }
+
+    if let Err(e) = fs::create_dir_all(scope.data_path("examples", "")) {
+        eprintln!("Failed to create examples folder: {e}");
+        process::exit(1);
+    }
+
+    for proposal in to_write {
+        match fs::write(&proposal.path, &proposal.block.input) {
+            Ok(()) => println!("Wrote example file \"{}\"", proposal.path),
+            Err(e) => {
+                eprintln!("Failed to write example file \"{}\": {e}", proposal.path);
+                process::exit(1);
+            }
+        }
+    }
+
+    if !cases.is_empty() {
+        match write_manifest(&manifest_path, &cases) {
+            Ok(()) => println!("Added {} case(s) to \"{manifest_path}\"", cases.len()),
+            Err(e) => {
+                eprintln!("Failed to write manifest \"{manifest_path}\": {e}");
+                process::exit(1);
+            }
+        }
+
+        println!("---");
+        println!(
+            "🎄 Add `advent_of_code::example_tests!();` to the tests of day {day} to check them."
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use std::process;
+
+use crate::template::client::Client;
+use crate::template::{Year, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(year: Year, id: u64) {
+    let leaderboard = Client::from_env(year).and_then(|client| client.get_leaderboard(id));
+
+    match leaderboard {
+        Ok(leaderboard) => {
+            println!(
+                "{ANSI_BOLD}Private leaderboard {id} ({}){ANSI_RESET}",
+                leaderboard.event
+            );
+            println!();
+            println!("{}", leaderboard.format_table());
+        }
+        Err(e) => {
+            eprintln!("failed to fetch leaderboard: {e}");
+            process::exit(1);
+        }
+    }
+}
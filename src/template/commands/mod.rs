@@ -1,6 +1,12 @@
 pub mod all;
 pub mod download;
+pub mod extract;
+#[cfg(feature = "client")]
+pub mod leaderboard;
+pub mod profile;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
 pub mod time;
+pub mod verify;
@@ -0,0 +1,77 @@
+use std::fs;
+use std::path::Path;
+use std::process::{self, Command, Stdio};
+
+use crate::template::run_multi::child_commands::{self, Profile};
+use crate::template::{Day, Scope, Year};
+
+fn is_perf_available() -> bool {
+    Command::new("perf")
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Runs one part of a day in a loop and records it with `perf`, if it is installed.
+pub fn handle(year: Year, day: Day, part: u8) {
+    if !(1..=2).contains(&part) {
+        eprintln!("--part must be 1 or 2.");
+        process::exit(1);
+    }
+
+    let scope = Scope::resolve(year, day);
+
+    if !Path::new(&scope.bin_path(day)).exists() {
+        eprintln!("Day {day} has not been scaffolded yet.");
+        process::exit(1);
+    }
+
+    // optimized, but with debug info so that the profile shows function names and lines.
+    if let Err(e) = child_commands::build_solution(scope, day, Profile::Profiling) {
+        eprintln!("Failed to build solution: {e:?}");
+        process::exit(1);
+    }
+
+    let executable = child_commands::get_path_for_executable(scope, day, Profile::Profiling);
+    let solution_args = ["--profile-part".to_string(), part.to_string()];
+
+    if !is_perf_available() {
+        eprintln!("`perf` was not found, so no profile can be recorded.");
+        eprintln!("Install it (e.g. `linux-tools` on Debian or Ubuntu), or attach another sampling profiler to:");
+        eprintln!("  {} {}", executable.display(), solution_args.join(" "));
+        process::exit(1);
+    }
+
+    let profiles_dir = child_commands::target_dir().join("profiles");
+    if let Err(e) = fs::create_dir_all(&profiles_dir) {
+        eprintln!("Failed to create \"{}\": {e}", profiles_dir.display());
+        process::exit(1);
+    }
+
+    let output = profiles_dir.join(format!("{}-part{part}.perf.data", scope.bin_name(day)));
+
+    let status = Command::new("perf")
+        .args(["record", "--call-graph", "dwarf", "--output"])
+        .arg(&output)
+        .arg("--")
+        .arg(&executable)
+        .args(&solution_args)
+        .status();
+
+    match status {
+        Ok(status) if status.success() => {
+            println!("---");
+            println!("🎄 Recorded profile to \"{}\".", output.display());
+            println!(
+                "🎄 Type `perf report --input {}` to inspect it.",
+                output.display()
+            );
+        }
+        _ => {
+            eprintln!("Failed to record profile with `perf`.");
+            process::exit(1);
+        }
+    }
+}
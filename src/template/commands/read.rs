@@ -1,15 +1,26 @@
 use std::process;
 
-use crate::template::{aoc_cli, Day};
+use crate::template::{Day, Year};
+
+#[cfg(not(feature = "client"))]
+pub fn handle(year: Year, day: Day) {
+    use crate::template::aoc_cli;
 
-pub fn handle(day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    if let Err(e) = aoc_cli::read(year, day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
 }
+
+#[cfg(feature = "client")]
+pub fn handle(year: Year, day: Day) {
+    if let Err(e) = crate::template::client::read(year, day) {
+        eprintln!("failed to read day {day}: {e}");
+        process::exit(1);
+    };
+}
@@ -1,73 +1,399 @@
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
-};
+use std::{fs, path::Path, process};
 
-use crate::template::Day;
+use crate::template::puzzle::Puzzle;
+use crate::template::status::is_non_empty;
+use crate::template::{Day, Scope, Year};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Directory with the module templates, `templates/<name>.txt`.
+const TEMPLATES_DIR: &str = "templates";
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+fn read_template(name: &str) -> Result<String, String> {
+    fs::read_to_string(format!("{TEMPLATES_DIR}/{name}.txt")).map_err(|_| {
+        let mut available: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                Some(
+                    entry
+                        .file_name()
+                        .to_str()?
+                        .strip_suffix(".txt")?
+                        .to_string(),
+                )
+            })
+            .collect();
+        available.sort();
+        format!(
+            "Unknown template `{name}`. Available templates: {}.",
+            available.join(", ")
+        )
+    })
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// The integer type in `-> Option<T>` of the `part_one` or `part_two` function of a template.
+fn answer_type(template: &str, part: u8) -> Option<&str> {
+    let function = match part {
+        1 => "fn part_one(",
+        _ => "fn part_two(",
+    };
+
+    let line = template.lines().find(|line| line.contains(function))?;
+    let (_, answer_type) = line.split_once("-> Option<")?;
+    Some(answer_type.split_once('>')?.0.trim())
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Whether `answer` is a literal of the integer type `answer_type`.
+fn fits(answer: &str, answer_type: &str) -> bool {
+    match answer_type {
+        "u8" => answer.parse::<u8>().is_ok(),
+        "u16" => answer.parse::<u16>().is_ok(),
+        "u32" => answer.parse::<u32>().is_ok(),
+        "u64" => answer.parse::<u64>().is_ok(),
+        "u128" => answer.parse::<u128>().is_ok(),
+        "usize" => answer.parse::<usize>().is_ok(),
+        "i8" => answer.parse::<i8>().is_ok(),
+        "i16" => answer.parse::<i16>().is_ok(),
+        "i32" => answer.parse::<i32>().is_ok(),
+        "i64" => answer.parse::<i64>().is_ok(),
+        "i128" => answer.parse::<i128>().is_ok(),
+        "isize" => answer.parse::<isize>().is_ok(),
+        _ => false,
+    }
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
+/// The expected value of a generated test: the example answer from the puzzle, if it is a
+/// number that fits the answer type of the template. Otherwise the test would not compile.
+fn expected_answer(template: &str, puzzle: Option<&Puzzle>, part: u8) -> String {
+    puzzle
+        .and_then(|puzzle| puzzle.example_answer(part))
+        .filter(|answer| answer_type(template, part).is_some_and(|ty| fits(answer, ty)))
+        .map_or("None".into(), |answer| format!("Some({answer})"))
+}
+
+/// Fills the placeholders of a module template.
+fn render(template: &str, year: Year, scope: Scope, day: Day, puzzle: Option<&Puzzle>) -> String {
+    let year_arg = match scope {
+        Scope::Flat => String::new(),
+        Scope::Year(year) => format!(", year = {year}"),
+    };
+
+    let title = match puzzle.and_then(Puzzle::title) {
+        Some(title) => format!("Day {}: {title}", day.into_inner()),
+        None => format!("Day {}", day.into_inner()),
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+    template
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%YEAR_ARG%", &year_arg)
+        .replace("%YEAR%", &year.to_string())
+        .replace("%TITLE%", &title)
+        .replace("%PART_ONE_ANSWER%", &expected_answer(template, puzzle, 1))
+        .replace("%PART_TWO_ANSWER%", &expected_answer(template, puzzle, 2))
+}
+
+/// What scaffolding does to a single file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Action {
+    Create,
+    /// The file exists and is left alone.
+    Skip,
+    /// The file exists and is replaced, because `--force` was passed.
+    Overwrite,
+    /// The data file exists but is empty, e.g. from an earlier scaffold, and is written again.
+    OverwriteEmpty,
+    /// The data file has content, which is never replaced by an empty file, even with `--force`.
+    Keep,
+}
+
+impl Action {
+    /// Input and example files are scaffolded empty, so they only replace files that are empty
+    /// as well, e.g. those left by an earlier scaffold.
+    fn plan(path: &str, is_data: bool, force: bool) -> Self {
+        if is_data {
+            return match (Path::new(path).exists(), is_non_empty(path)) {
+                (false, _) => Action::Create,
+                (true, false) => Action::OverwriteEmpty,
+                (true, true) => Action::Keep,
+            };
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+
+        match (Path::new(path).exists(), force) {
+            (false, _) => Action::Create,
+            (true, false) => Action::Skip,
+            (true, true) => Action::Overwrite,
         }
     }
+}
+
+/// A file created by scaffolding.
+struct ScaffoldFile {
+    /// e.g. `module file`.
+    kind: &'static str,
+    path: String,
+    contents: String,
+    action: Action,
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+impl ScaffoldFile {
+    fn new(kind: &'static str, path: String, contents: String, force: bool) -> Self {
+        let action = Action::plan(&path, contents.is_empty(), force);
+        ScaffoldFile {
+            kind,
+            path,
+            contents,
+            action,
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    }
+
+    fn write(&self) -> Result<(), std::io::Error> {
+        if let Some(dir) = Path::new(&self.path).parent() {
+            fs::create_dir_all(dir)?;
         }
+        fs::write(&self.path, &self.contents)
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    fn report(&self, dry_run: bool) -> String {
+        let (kind, path) = (self.kind, &self.path);
+        match (self.action, dry_run) {
+            (Action::Create, false) => format!("Created {kind} \"{path}\""),
+            (Action::Create, true) => format!("Would create {kind} \"{path}\""),
+            (Action::Overwrite, false) => format!("Overwrote {kind} \"{path}\""),
+            (Action::Overwrite, true) => format!("Would overwrite {kind} \"{path}\""),
+            (Action::OverwriteEmpty, false) => format!("Overwrote empty {kind} \"{path}\""),
+            (Action::OverwriteEmpty, true) => format!("Would overwrite empty {kind} \"{path}\""),
+            (Action::Skip, _) => {
+                format!("Skipped {kind} \"{path}\" (already exists, use --force to overwrite)")
+            }
+            (Action::Keep, _) => format!("Skipped {kind} \"{path}\" (not empty)"),
         }
+    }
+}
+
+/// Creates the module, input and example file of a day. An existing module is skipped, unless
+/// `force` is set, and input and example files with content are always kept. With `dry_run`,
+/// only reports what would be done.
+pub fn handle(year: Year, day: Day, template: &str, force: bool, dry_run: bool) {
+    let scope = Scope::for_year(year);
+
+    let template = match read_template(template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("{e}");
             process::exit(1);
         }
+    };
+
+    // the puzzle description is only available if it was downloaded before.
+    let puzzle = Puzzle::read(scope, day);
+
+    let files = [
+        ScaffoldFile::new(
+            "module file",
+            scope.bin_path(day),
+            render(&template, year, scope, day, puzzle.as_ref()),
+            force,
+        ),
+        ScaffoldFile::new("input file", scope.input_path(day), String::new(), force),
+        ScaffoldFile::new(
+            "example file",
+            scope.example_path(day),
+            String::new(),
+            force,
+        ),
+    ];
+
+    for file in &files {
+        if !dry_run
+            && matches!(
+                file.action,
+                Action::Create | Action::Overwrite | Action::OverwriteEmpty
+            )
+        {
+            if let Err(e) = file.write() {
+                eprintln!("Failed to write {} \"{}\": {e}", file.kind, file.path);
+                process::exit(1);
+            }
+        }
+        println!("{}", file.report(dry_run));
+    }
+
+    if dry_run {
+        return;
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    match scope {
+        Scope::Flat => println!("🎄 Type `cargo solve {day}` to run your solution."),
+        Scope::Year(year) => {
+            println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{expected_answer, render, Action, ScaffoldFile};
+    use crate::template::{puzzle::Puzzle, Scope};
+    use crate::{day, year};
+
+    const TEMPLATE: &str = "// %TITLE%\n\
+        // https://adventofcode.com/%YEAR%/day/%DAY_NUMBER%\n\
+        advent_of_code::solution!(%DAY_NUMBER%%YEAR_ARG%);\n\
+        pub fn part_one(input: &str) -> Option<u32> {}\n\
+        pub fn part_two(input: &str) -> Option<i64> {}\n\
+        assert_eq!(result, %PART_ONE_ANSWER%);\n\
+        assert_eq!(result, %PART_TWO_ANSWER%);\n";
+
+    fn puzzle(one: &str, two: &str) -> Puzzle {
+        Puzzle {
+            markdown: format!(
+                "## --- Day 1: Report Repair ---\n\nthe answer is `*{one}*`.\n\n\
+                ## --- Part Two ---\n\nthe answer is `*{two}*`.\n"
+            ),
+        }
+    }
+
+    #[test]
+    fn renders_templates() {
+        let puzzle = puzzle("514579", "241861950");
+
+        assert_eq!(
+            render(TEMPLATE, year!(2020), Scope::Flat, day!(1), Some(&puzzle)),
+            "// Day 1: Report Repair\n\
+            // https://adventofcode.com/2020/day/1\n\
+            advent_of_code::solution!(1);\n\
+            pub fn part_one(input: &str) -> Option<u32> {}\n\
+            pub fn part_two(input: &str) -> Option<i64> {}\n\
+            assert_eq!(result, Some(514579));\n\
+            assert_eq!(result, Some(241861950));\n"
+        );
+
+        let rendered = render(
+            TEMPLATE,
+            year!(2021),
+            Scope::Year(year!(2021)),
+            day!(3),
+            None,
+        );
+        assert!(rendered.starts_with("// Day 3\n// https://adventofcode.com/2021/day/3\n"));
+        assert!(rendered.contains("advent_of_code::solution!(3, year = 2021);"));
+        assert!(rendered.contains("assert_eq!(result, None);\nassert_eq!(result, None);"));
+    }
+
+    #[test]
+    fn fits_answers_to_template() {
+        let answer = |one: &str, two: &str, part: u8| {
+            expected_answer(TEMPLATE, Some(&puzzle(one, two)), part)
+        };
+
+        assert_eq!(answer("4294967295", "0", 1), "Some(4294967295)");
+        assert_eq!(answer("4294967296", "0", 1), "None");
+        assert_eq!(answer("-1", "0", 1), "None");
+        assert_eq!(answer("0", "-4294967296", 2), "Some(-4294967296)");
+        assert_eq!(answer("0", "EFHJ", 2), "None");
+        assert_eq!(expected_answer(TEMPLATE, None, 1), "None");
+        // answers of templates that do not return an integer are left to the solver.
+        assert_eq!(
+            expected_answer(
+                "pub fn part_one(input: &str) -> Option<String> {}",
+                Some(&puzzle("42", "0")),
+                1
+            ),
+            "None"
+        );
+    }
+
+    #[test]
+    fn plans_actions() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = |name: &str| dir.join(name).display().to_string();
+        fs::write(path("empty.txt"), "").unwrap();
+        fs::write(path("filled.txt"), "1721\n979\n").unwrap();
+
+        for force in [false, true] {
+            assert_eq!(
+                Action::plan(&path("missing.txt"), true, force),
+                Action::Create
+            );
+            assert_eq!(
+                Action::plan(&path("empty.txt"), true, force),
+                Action::OverwriteEmpty
+            );
+            assert_eq!(Action::plan(&path("filled.txt"), true, force), Action::Keep);
+            assert_eq!(
+                Action::plan(&path("missing.rs"), false, force),
+                Action::Create
+            );
+        }
+
+        assert_eq!(Action::plan(&path("empty.txt"), false, false), Action::Skip);
+        assert_eq!(
+            Action::plan(&path("filled.txt"), false, false),
+            Action::Skip
+        );
+        assert_eq!(
+            Action::plan(&path("filled.txt"), false, true),
+            Action::Overwrite
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reports_actions() {
+        let report = |action: Action, dry_run: bool| {
+            ScaffoldFile {
+                kind: "input file",
+                path: "data/inputs/01.txt".into(),
+                contents: String::new(),
+                action,
+            }
+            .report(dry_run)
+        };
+
+        assert_eq!(
+            report(Action::Create, false),
+            "Created input file \"data/inputs/01.txt\""
+        );
+        assert_eq!(
+            report(Action::Create, true),
+            "Would create input file \"data/inputs/01.txt\""
+        );
+        assert_eq!(
+            report(Action::Overwrite, false),
+            "Overwrote input file \"data/inputs/01.txt\""
+        );
+        assert_eq!(
+            report(Action::Overwrite, true),
+            "Would overwrite input file \"data/inputs/01.txt\""
+        );
+        assert_eq!(
+            report(Action::OverwriteEmpty, false),
+            "Overwrote empty input file \"data/inputs/01.txt\""
+        );
+        assert_eq!(
+            report(Action::OverwriteEmpty, true),
+            "Would overwrite empty input file \"data/inputs/01.txt\""
+        );
+
+        for dry_run in [false, true] {
+            assert_eq!(
+                report(Action::Skip, dry_run),
+                "Skipped input file \"data/inputs/01.txt\" (already exists, use --force to overwrite)"
+            );
+            assert_eq!(
+                report(Action::Keep, dry_run),
+                "Skipped input file \"data/inputs/01.txt\" (not empty)"
+            );
+        }
+    }
 }
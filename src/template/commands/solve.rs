@@ -1,9 +1,19 @@
-use std::process::{Command, Stdio};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::report::{OutputFormat, PartReport};
+use crate::template::runner::FAILED;
+use crate::template::watch::Watcher;
+use crate::template::{
+    examples, registry, Day, Scope, Year, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Arguments for `cargo run` that select the solution bin and the build profile.
+fn build_args(bin_name: &str, release: bool, dhat: bool) -> Vec<String> {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), bin_name.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -16,6 +26,26 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push("--release".to_string());
     }
 
+    cmd_args
+}
+
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    format: OutputFormat,
+    input: Option<String>,
+) {
+    let bin_name = Scope::resolve(year, day).bin_name(day);
+    let mut cmd_args = build_args(&bin_name, release, dhat);
+
+    // submit via the built-in client if the solution is built with it, too.
+    if cfg!(feature = "client") && submit_part.is_some() {
+        cmd_args.extend(["--features".to_string(), "client".to_string()]);
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -23,6 +53,16 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if format != OutputFormat::Human {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input);
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -32,3 +72,126 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Re-runs the example tests and the real input of a day whenever its files change.
+pub fn watch(year: Year, day: Day, release: bool, dhat: bool) {
+    let scope = Scope::resolve(year, day);
+    let bin_name = scope.bin_name(day);
+    let mut watcher = Watcher::new(scope, day);
+    let mut answers: HashMap<u8, Option<String>> = HashMap::new();
+
+    loop {
+        print!("{ANSI_CLEAR_SCREEN}");
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET} (watching for changes, press Ctrl+C to stop)");
+        println!();
+
+        let tests_passed = Command::new("cargo")
+            .args(["test", "--quiet", "--bin", &bin_name])
+            .status()
+            .is_ok_and(|status| status.success());
+
+        if tests_passed {
+            println!("{ANSI_GREEN}Example tests passed.{ANSI_RESET}");
+        } else {
+            println!("{ANSI_RED}Example tests failed.{ANSI_RESET}");
+        }
+        println!();
+
+        match run_json(&bin_name, release, dhat) {
+            Some(reports) => print_changes(&reports, &mut answers),
+            None => println!("{ANSI_RED}Failed to run the solution.{ANSI_RESET}"),
+        }
+
+        watcher.wait_for_change();
+    }
+}
+
+/// Runs the solution with `--format json`, forwarding all output that is not a report.
+fn run_json(bin_name: &str, release: bool, dhat: bool) -> Option<Vec<PartReport>> {
+    let mut cmd = Command::new("cargo")
+        .args(build_args(bin_name, release, dhat))
+        .args(["--quiet", "--", "--format", "json"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .ok()?;
+
+    let stdout = BufReader::new(cmd.stdout.take()?);
+    let mut reports = vec![];
+
+    for line in stdout.lines() {
+        let line = line.ok()?;
+        match line.parse::<PartReport>() {
+            Ok(report) => reports.push(report),
+            Err(_) => println!("{line}"),
+        }
+    }
+
+    cmd.wait().ok()?.success().then_some(reports)
+}
+
+/// Prints the answers of a run, highlighting those that differ from the previous run.
+fn print_changes(reports: &[PartReport], answers: &mut HashMap<u8, Option<String>>) {
+    for report in reports {
+        if report.is_parse() {
+            let status = if report.error.is_some() {
+                format!("{ANSI_RED}{FAILED}{ANSI_RESET} ")
+            } else {
+                String::new()
+            };
+            println!("Parse: {status}({:.1?})", report.stats.mean);
+            continue;
+        }
+
+        let answer = match (&report.answer, &report.error) {
+            (Some(answer), _) => answer.as_str(),
+            (None, Some(_)) => FAILED,
+            (None, None) => "✖",
+        };
+        let duration = format!("({:.1?})", report.stats.mean);
+
+        match answers.insert(report.part, report.answer.clone()) {
+            Some(previous) if previous != report.answer => {
+                let previous = previous.as_deref().unwrap_or("✖");
+                println!(
+                    "Part {}: {ANSI_BOLD}{ANSI_GREEN}{answer}{ANSI_RESET} {duration} (changed from {previous})",
+                    report.part
+                );
+            }
+            _ => println!(
+                "Part {}: {ANSI_BOLD}{answer}{ANSI_RESET} {duration}",
+                report.part
+            ),
+        }
+    }
+}
+
+/// Runs `part` of the solution of a day, or both parts, over its example files, in-process.
+pub fn example_files(year: Year, day: Day, selection: examples::Selection, part: Option<u8>) {
+    let Some(solution) = registry::find(year, day) else {
+        eprintln!("No solution found for day {day}.");
+        process::exit(1);
+    };
+
+    if let Err(e) = examples::run_files(solution, selection, part) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Checks the solution of a day against the cases in its example manifest, in-process.
+pub fn examples(year: Year, day: Day, include_slow: bool) {
+    let Some(solution) = registry::find(year, day) else {
+        eprintln!("No solution found for day {day}.");
+        process::exit(1);
+    };
+
+    match examples::run(solution, include_slow) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
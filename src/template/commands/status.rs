@@ -0,0 +1,12 @@
+use crate::template::report::OutputFormat;
+use crate::template::status;
+use crate::template::Year;
+
+pub fn handle(year: Year, format: OutputFormat) {
+    let days = status::read_all(year);
+
+    match format {
+        OutputFormat::Human => println!("{}", status::format_table(year, &days)),
+        OutputFormat::Json => println!("{}", status::to_json(year, &days)),
+    }
+}
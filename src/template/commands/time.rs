@@ -1,15 +1,33 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::compare::{self, DEFAULT_THRESHOLD};
+use crate::template::report::OutputFormat;
 use crate::template::run_multi::run_multi;
+use crate::template::runner::{BenchConfig, RunOptions};
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, readme_benchmarks, Day, Executor, Scope, Year};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    memory: bool,
+    format: OutputFormat,
+    compare: bool,
+    threshold: Option<f64>,
+    bench: BenchConfig,
+    executor: Executor,
+) {
+    let scope = Scope::for_year(year);
+    let stored_timings = Timings::read_from_file(scope);
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
+            // comparing needs fresh timings for days that are already benched.
+            if run_all || compare {
                 all_days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
@@ -21,20 +39,72 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = RunOptions {
+        time: true,
+        format,
+        bench,
+        ..RunOptions::default()
+    };
+
+    // timed runs only return no timings if the solutions could not be built, which was reported.
+    let Some(timings) = run_multi(year, &days_to_run, true, memory, &options, executor) else {
+        process::exit(1);
+    };
+
+    let mut has_regressions = false;
+
+    if compare {
+        let threshold = threshold.unwrap_or(DEFAULT_THRESHOLD);
+        let changes = compare::compare(&stored_timings, &timings);
+        let table = compare::format_table(&changes, threshold);
+
+        let (failed, slower): (Vec<_>, Vec<_>) = changes
+            .iter()
+            .filter(|c| c.is_regression(threshold))
+            .partition(|c| c.current_nanos.is_none());
+        has_regressions = !failed.is_empty() || !slower.is_empty();
+
+        // keep stdout parseable when emitting JSON records.
+        if format == OutputFormat::Human {
+            println!();
+            println!("{table}");
+        } else {
+            eprintln!("{table}");
+        }
+
+        if !slower.is_empty() {
+            eprintln!(
+                "{} part(s) regressed by more than {threshold}%.",
+                slower.len()
+            );
+        }
+        if !failed.is_empty() {
+            eprintln!(
+                "{} part(s) no longer have a timing, because they failed or were aborted.",
+                failed.len()
+            );
+        }
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(scope).unwrap();
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(scope, merged_timings) {
             Ok(()) => {
-                println!("Stored updated benchmarks.");
+                // keep stdout parseable when emitting JSON records.
+                if format == OutputFormat::Human {
+                    println!();
+                    println!("Stored updated benchmarks.");
+                }
             }
             Err(_) => {
                 eprintln!("Failed to store updated benchmarks.");
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,73 @@
+use std::process;
+
+use crate::template::answers::{self, Answers, Verdict};
+use crate::template::run_multi::run_in_process;
+use crate::template::runner::RunOptions;
+use crate::template::{
+    all_days, registry, Day, Scope, Year, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET,
+};
+
+pub fn handle(year: Year, day: Option<Day>) {
+    let days: Vec<Day> = day.map_or_else(
+        // only check days that have been scaffolded.
+        || {
+            all_days()
+                .filter(|day| registry::find(year, *day).is_some())
+                .collect()
+        },
+        |day| vec![day],
+    );
+
+    // only the verdicts are printed, not the answers and timings of every part.
+    let options = RunOptions {
+        quiet: true,
+        ..RunOptions::default()
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    println!("{:<6} {:<6} Verdict", "Day", "Part");
+
+    for day in days {
+        let scope = Scope::resolve(year, day);
+        let reports = run_in_process(year, day, &options).unwrap_or_default();
+        let answers = Answers::read(scope, day);
+
+        for part in 1..=2 {
+            let actual = reports
+                .iter()
+                .find(|report| report.part == part)
+                .and_then(|report| report.answer.as_deref());
+
+            let verdict = match answers.verify(part, actual) {
+                Verdict::Pass => {
+                    passed += 1;
+                    format!("{ANSI_GREEN}pass{ANSI_RESET}")
+                }
+                Verdict::Fail { expected, actual } => {
+                    failed += 1;
+                    let actual = actual.as_deref().unwrap_or("no answer");
+                    format!("{ANSI_RED}fail{ANSI_RESET} (expected {expected}, got {actual})")
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    format!(
+                        "missing (no answer in \"{}\")",
+                        answers::get_path(scope, day)
+                    )
+                }
+            };
+
+            println!("{:<6} {part:<6} {verdict}", day.to_string());
+        }
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Verified:{ANSI_RESET} {passed} passed, {failed} failed, {missing} missing"
+    );
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
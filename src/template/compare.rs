@@ -0,0 +1,280 @@
+/// Module that compares fresh benchmark timings against a stored baseline.
+use crate::template::report::PARSE;
+use crate::template::timings::{format_nanos, Abort, PartTiming, Timing, Timings};
+use crate::template::{Day, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+/// Relative slowdown, in percent, above which a part is considered a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Parses a `--threshold` in percent, which must be a non-negative number.
+pub fn parse_threshold(s: &str) -> Result<f64, String> {
+    s.parse()
+        .ok()
+        .filter(|threshold: &f64| *threshold >= 0.0)
+        .ok_or(format!("expected a non-negative percentage, got `{s}`."))
+}
+
+/// The change in execution time of a single part between two runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub day: Day,
+    pub part: u8,
+    pub baseline_nanos: f64,
+    /// `None` if the part has no timing anymore, because it failed or was aborted.
+    pub current_nanos: Option<f64>,
+    pub aborted: Option<Abort>,
+}
+
+impl Change {
+    /// Relative change in percent. Positive values are slowdowns.
+    pub fn percent(&self) -> Option<f64> {
+        let current = self.current_nanos?;
+        if self.baseline_nanos == 0.0 {
+            return Some(0.0);
+        }
+        Some((current - self.baseline_nanos) / self.baseline_nanos * 100.0)
+    }
+
+    /// Whether the part got slower by more than `threshold` percent, or lost its timing.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent().is_none_or(|percent| percent > threshold)
+    }
+
+    pub fn is_improvement(&self, threshold: f64) -> bool {
+        self.percent().is_some_and(|percent| percent < -threshold)
+    }
+}
+
+fn parts(timing: &Timing) -> [(u8, Option<&PartTiming>); 3] {
+    [
+        (PARSE, timing.parse.as_ref()),
+        (1, timing.part_1.as_ref()),
+        (2, timing.part_2.as_ref()),
+    ]
+}
+
+/// Pairs up every part of `current` that has a timing in `baseline`. Parts without a current
+/// timing are included, as they failed or were aborted.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<Change> {
+    let mut changes = vec![];
+
+    for timing in &current.data {
+        let Some(stored) = baseline.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        for ((part, current), (_, stored)) in parts(timing).into_iter().zip(parts(stored)) {
+            let Some(stored) = stored else {
+                continue;
+            };
+
+            changes.push(Change {
+                day: timing.day,
+                part,
+                baseline_nanos: stored.nanos,
+                current_nanos: current.map(|current| current.nanos),
+                aborted: timing.aborted,
+            });
+        }
+    }
+
+    changes.sort_unstable_by_key(|c| (c.day, c.part));
+    changes
+}
+
+/// Renders a table of `changes`, highlighting parts that changed by more than `threshold` percent.
+pub fn format_table(changes: &[Change], threshold: f64) -> String {
+    let mut lines: Vec<String> = vec![
+        format!("{ANSI_BOLD}Comparison against stored timings{ANSI_RESET}"),
+        String::new(),
+        format!(
+            "{:<6} {:<6} {:>12} {:>12} {:>9}",
+            "Day", "Part", "Baseline", "Current", "Change"
+        ),
+    ];
+
+    if changes.is_empty() {
+        lines.push("No stored timings to compare against.".into());
+    }
+
+    for change in changes {
+        let color = if change.is_regression(threshold) {
+            ANSI_RED
+        } else if change.is_improvement(threshold) {
+            ANSI_GREEN
+        } else {
+            ""
+        };
+
+        let part = if change.part == PARSE {
+            "parse".to_string()
+        } else {
+            change.part.to_string()
+        };
+
+        let (current, percent) = match (change.current_nanos, change.percent()) {
+            (Some(current), Some(percent)) => (format_nanos(current), format!("{percent:>+8.1}%")),
+            // the part failed, or the day was aborted before it finished.
+            _ => (
+                "-".into(),
+                change
+                    .aborted
+                    .map_or("failed".into(), |abort| abort.to_string()),
+            ),
+        };
+
+        lines.push(format!(
+            "{:<6} {:<6} {:>12} {:>12} {color}{percent:>9}{ANSI_RESET}",
+            change.day.to_string(),
+            part,
+            format_nanos(change.baseline_nanos),
+            current,
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, format_table, parse_threshold};
+    use crate::{
+        day,
+        template::timings::{parse_duration, Abort, PartTiming, Timing, Timings},
+    };
+
+    fn timing(day: crate::template::Day, part_1: Option<&str>, part_2: Option<&str>) -> Timing {
+        let part = |s: Option<&str>| {
+            s.map(|s| PartTiming {
+                nanos: parse_duration(s).unwrap(),
+                samples: None,
+                memory: None,
+            })
+        };
+
+        Timing {
+            part_1: part(part_1),
+            part_2: part(part_2),
+            ..Timing::new(day)
+        }
+    }
+
+    #[test]
+    fn compares_matching_parts() {
+        let baseline = Timings {
+            data: vec![timing(day!(1), Some("10.0ms"), Some("20.0ms"))],
+        };
+        let current = Timings {
+            data: vec![timing(day!(1), Some("12.0ms"), Some("10.0ms"))],
+        };
+
+        let changes = compare(&baseline, &current);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].part, 1);
+        assert!((changes[0].percent().unwrap() - 20.0).abs() < 1e-6);
+        assert!(changes[0].is_regression(10.0));
+        assert!(!changes[0].is_regression(25.0));
+        assert!((changes[1].percent().unwrap() + 50.0).abs() < 1e-6);
+        assert!(changes[1].is_improvement(10.0));
+    }
+
+    #[test]
+    fn compares_across_units() {
+        let baseline = Timings {
+            data: vec![timing(day!(3), Some("900.0µs"), None)],
+        };
+        let current = Timings {
+            data: vec![timing(day!(3), Some("1.8ms"), None)],
+        };
+
+        let changes = compare(&baseline, &current);
+        assert_eq!(changes.len(), 1);
+        assert!((changes[0].percent().unwrap() - 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn reports_parts_that_lost_their_timing() {
+        let baseline = Timings {
+            data: vec![
+                timing(day!(1), Some("10.0ms"), Some("10.0ms")),
+                timing(day!(2), Some("10.0ms"), None),
+            ],
+        };
+        let mut aborted = timing(day!(2), None, None);
+        aborted.aborted = Some(Abort::Timeout);
+        let current = Timings {
+            data: vec![timing(day!(1), Some("10.0ms"), None), aborted],
+        };
+
+        let changes = compare(&baseline, &current);
+        assert_eq!(changes.len(), 3);
+        assert!(!changes[0].is_regression(10.0));
+        assert_eq!(changes[1].current_nanos, None);
+        assert!(changes[1].is_regression(10.0));
+        assert!(!changes[1].is_improvement(10.0));
+        assert!(changes[2].is_regression(1000.0));
+
+        let table = format_table(&changes, 10.0);
+        assert!(table.contains("failed"));
+        assert!(table.contains("TIMEOUT"));
+    }
+
+    #[test]
+    fn parses_thresholds() {
+        assert_eq!(parse_threshold("5"), Ok(5.0));
+        assert_eq!(parse_threshold("0"), Ok(0.0));
+        assert_eq!(parse_threshold("2.5"), Ok(2.5));
+        assert!(parse_threshold("-5").is_err());
+        assert!(parse_threshold("NaN").is_err());
+        assert!(parse_threshold("much").is_err());
+    }
+
+    #[test]
+    fn skips_parts_missing_from_either_side() {
+        let baseline = Timings {
+            data: vec![
+                timing(day!(1), Some("10.0ms"), None),
+                timing(day!(2), Some("10.0ms"), Some("10.0ms")),
+            ],
+        };
+        let current = Timings {
+            data: vec![
+                timing(day!(1), Some("10.0ms"), Some("10.0ms")),
+                timing(day!(4), Some("10.0ms"), Some("10.0ms")),
+            ],
+        };
+
+        let changes = compare(&baseline, &current);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].day, day!(1));
+    }
+
+    #[test]
+    fn compares_parse_stages() {
+        let mut baseline = timing(day!(1), Some("10.0ms"), None);
+        baseline.parse = Some(PartTiming {
+            nanos: 1_000_000.0,
+            samples: None,
+            memory: None,
+        });
+        let mut current = timing(day!(1), Some("10.0ms"), None);
+        current.parse = Some(PartTiming {
+            nanos: 3_000_000.0,
+            samples: None,
+            memory: None,
+        });
+
+        let changes = compare(
+            &Timings {
+                data: vec![baseline],
+            },
+            &Timings {
+                data: vec![current],
+            },
+        );
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].part, 0);
+        assert!(changes[0].is_regression(10.0));
+        assert!(format_table(&changes, 10.0).contains("parse"));
+    }
+}
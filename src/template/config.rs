@@ -0,0 +1,313 @@
+/// Module that reads `aoc.toml`, the optional config file in the root of the repository.
+use std::{fs, io, process, str::FromStr, sync::OnceLock};
+
+use crate::template::runner::BenchConfig;
+use crate::template::toml::{parse_tables, Table};
+use crate::template::Year;
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Settings of the template. Every setting is optional and defaults to the layout of this repository.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    /// The year solved in this repository. Takes precedence over `AOC_YEAR`.
+    pub year: Option<Year>,
+    /// The `[paths]` table.
+    pub paths: Paths,
+    /// The `[readme]` table.
+    pub readme: Readme,
+    /// The `[bench]` table.
+    pub bench: BenchConfig,
+    /// `policy` in the `[submit]` table.
+    pub submit: SubmitPolicy,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Paths {
+    /// Root of inputs, examples, puzzles and stored results. `AOC_DATA_DIR` takes precedence.
+    pub data: String,
+    /// Directory of the solution binaries.
+    pub bins: String,
+    /// Stored timings, relative to the data directory of a year.
+    pub timings: String,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Self {
+            data: "data".into(),
+            bins: "src/bin".into(),
+            timings: "timings.json".into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Readme {
+    pub path: String,
+    /// Surrounds the benchmark table of the configured year. Tables of other years insert their
+    /// year before the closing `--->`.
+    pub marker: String,
+}
+
+impl Default for Readme {
+    fn default() -> Self {
+        Self {
+            path: "README.md".into(),
+            marker: "<!--- benchmarking table --->".into(),
+        }
+    }
+}
+
+/// Which answers `cargo solve --submit` sends to Advent of Code.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SubmitPolicy {
+    /// Answers that are known to be wrong from earlier submissions are not submitted again.
+    #[default]
+    Check,
+    /// Every answer is submitted.
+    Always,
+    /// Submitting is disabled.
+    Never,
+}
+
+impl FromStr for SubmitPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "check" => Ok(SubmitPolicy::Check),
+            "always" => Ok(SubmitPolicy::Always),
+            "never" => Ok(SubmitPolicy::Never),
+            _ => Err(format!("expected `check`, `always` or `never`, got `{s}`.")),
+        }
+    }
+}
+
+impl Config {
+    /// Reads `aoc.toml` when it is first called. Missing settings, or a missing file, fall back
+    /// to the defaults.
+    pub fn load() -> Result<&'static Config, String> {
+        if let Some(config) = CONFIG.get() {
+            return Ok(config);
+        }
+
+        let config = Self::read()?;
+        Ok(CONFIG.get_or_init(|| config))
+    }
+
+    /// The config loaded by [`Config::load`]. Exits if `aoc.toml` is invalid.
+    pub fn get() -> &'static Config {
+        Self::load().unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        })
+    }
+
+    fn read() -> Result<Self, String> {
+        match fs::read_to_string(CONFIG_PATH) {
+            Ok(s) => Self::parse(&s).map_err(|e| format!("Invalid {CONFIG_PATH}: {e}")),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Could not read {CONFIG_PATH}: {e}")),
+        }
+    }
+
+    fn parse(s: &str) -> Result<Self, String> {
+        let mut config = Config::default();
+
+        for mut table in parse_tables(s)? {
+            match table.name.as_str() {
+                "" => config.year = read_year(&mut table)?,
+                "paths" => config.paths = read_paths(&mut table)?,
+                "readme" => config.readme = read_readme(&mut table)?,
+                "bench" => config.bench = read_bench(&mut table)?,
+                "submit" => config.submit = read_submit(&mut table)?,
+                name => return Err(format!("unknown table `[{name}]`.")),
+            }
+            table.finish()?;
+        }
+
+        Ok(config)
+    }
+}
+
+fn read_year(table: &mut Table) -> Result<Option<Year>, String> {
+    table
+        .integer("year")?
+        .map(|year| {
+            year.to_string()
+                .parse()
+                .map_err(|e| format!("`year`: {e}."))
+        })
+        .transpose()
+}
+
+/// Reads a path that must not be empty.
+fn read_path(table: &mut Table, key: &str, default: String) -> Result<String, String> {
+    match table.string(key)? {
+        None => Ok(default),
+        Some(path) if path.is_empty() => Err(format!("`{}.{key}` must not be empty.", table.name)),
+        Some(path) => Ok(path),
+    }
+}
+
+fn read_paths(table: &mut Table) -> Result<Paths, String> {
+    let defaults = Paths::default();
+
+    Ok(Paths {
+        data: read_path(table, "data", defaults.data)?,
+        bins: read_path(table, "bins", defaults.bins)?,
+        timings: read_path(table, "timings", defaults.timings)?,
+    })
+}
+
+fn read_readme(table: &mut Table) -> Result<Readme, String> {
+    let defaults = Readme::default();
+
+    Ok(Readme {
+        path: read_path(table, "path", defaults.path)?,
+        marker: read_path(table, "marker", defaults.marker)?,
+    })
+}
+
+fn read_bench(table: &mut Table) -> Result<BenchConfig, String> {
+    let mut bench = BenchConfig::default();
+
+    if let Some(budget) = table.string("budget")? {
+        bench.budget = BenchConfig::parse_duration(&budget)?;
+    }
+    if let Some(warmup) = table.string("warmup")? {
+        bench.warmup = BenchConfig::parse_duration(&warmup)?;
+    }
+    if let Some(min_samples) = table.integer("min_samples")? {
+        bench.min_samples = min_samples.into();
+    }
+    if let Some(max_samples) = table.integer("max_samples")? {
+        bench.max_samples = max_samples.into();
+    }
+
+    bench.validate()?;
+    Ok(bench)
+}
+
+fn read_submit(table: &mut Table) -> Result<SubmitPolicy, String> {
+    table
+        .string("policy")?
+        .map_or(Ok(SubmitPolicy::default()), |policy| {
+            policy.parse().map_err(|e| format!("`submit.policy`: {e}"))
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Config, Paths, Readme, SubmitPolicy};
+    use crate::template::runner::BenchConfig;
+    use crate::year;
+
+    #[test]
+    fn reads_defaults() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+
+        let config = Config::default();
+        assert_eq!(config.year, None);
+        assert_eq!(config.paths.data, "data");
+        assert_eq!(config.paths.bins, "src/bin");
+        assert_eq!(config.readme.path, "README.md");
+        assert_eq!(config.submit, SubmitPolicy::Check);
+    }
+
+    #[test]
+    fn reads_config() {
+        let config = Config::parse(
+            r#"
+year = 2021
+
+[paths]
+data = "aoc-data"
+bins = "src/solutions"
+timings = "benchmarks.json"
+
+[readme]
+path = "docs/README.md"
+marker = "<!-- timings -->"
+
+[submit]
+policy = "never"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                year: Some(year!(2021)),
+                paths: Paths {
+                    data: "aoc-data".into(),
+                    bins: "src/solutions".into(),
+                    timings: "benchmarks.json".into(),
+                },
+                readme: Readme {
+                    path: "docs/README.md".into(),
+                    marker: "<!-- timings -->".into(),
+                },
+                bench: BenchConfig::default(),
+                submit: SubmitPolicy::Never,
+            }
+        );
+    }
+
+    #[test]
+    fn reads_bench_config() {
+        let config = Config::parse(
+            "[bench]\nbudget = \"2s\"\nwarmup = \"0\"\nmin_samples = 5\nmax_samples = 500\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.bench,
+            BenchConfig {
+                budget: Duration::from_secs(2),
+                warmup: Duration::ZERO,
+                min_samples: 5,
+                max_samples: 500,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let error = |s: &str| Config::parse(s).err().unwrap();
+
+        assert_eq!(
+            error("[bench]\nbudgett = \"2s\""),
+            "unknown key `bench.budgett`."
+        );
+        assert_eq!(error("[benches]"), "unknown table `[benches]`.");
+        assert_eq!(
+            error("[bench]\nmin_samples = \"10\""),
+            "`bench.min_samples` must be a non-negative integer."
+        );
+        assert_eq!(
+            error("[bench]\nmin_samples = 100\nmax_samples = 10"),
+            "the minimum number of samples (100) exceeds the maximum (10)."
+        );
+        assert_eq!(
+            error("year = 1999"),
+            "`year`: expecting a year from 2015 onwards."
+        );
+        assert_eq!(
+            error("[paths]\ndata = \"\""),
+            "`paths.data` must not be empty."
+        );
+        assert_eq!(
+            error("[submit]\npolicy = \"sometimes\""),
+            "`submit.policy`: expected `check`, `always` or `never`, got `sometimes`."
+        );
+    }
+}
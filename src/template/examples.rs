@@ -0,0 +1,371 @@
+/// Module that checks solutions against the example cases listed in a per-day manifest.
+use std::{fs, panic, path::Path, str::FromStr, time::Instant};
+
+use crate::template::registry::Solution;
+use crate::template::{Day, Scope, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+pub use crate::template::manifest::{Example, Manifest};
+
+impl Manifest {
+    pub fn read(scope: Scope, day: Day) -> Result<Self, String> {
+        let path = get_path(scope, day);
+        let s = fs::read_to_string(&path).map_err(|e| format!("could not read \"{path}\": {e}"))?;
+        s.parse().map_err(|e| format!("{path}: {e}"))
+    }
+}
+
+#[must_use]
+pub fn get_path(scope: Scope, day: Day) -> String {
+    scope.data_path("examples", &format!("{day}.manifest"))
+}
+
+/// The outcome of running a single example case.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        actual: Option<String>,
+    },
+    /// The example file could not be read, or the solution failed or panicked.
+    Error(String),
+}
+
+fn run_example(solution: &Solution, example: &Example) -> Outcome {
+    let path = solution.scope.data_path("examples", &example.file);
+    let Ok(input) = fs::read_to_string(&path) else {
+        return Outcome::Error(format!("could not read \"{path}\""));
+    };
+
+    match panic::catch_unwind(|| (solution.answer)(example.part, &input)) {
+        Ok(Ok(Some(actual))) if actual == example.expected => Outcome::Pass,
+        Ok(Ok(actual)) => Outcome::Fail { actual },
+        Ok(Err(e)) => Outcome::Error(e),
+        Err(_) => Outcome::Error("solution panicked".into()),
+    }
+}
+
+/// Checks a single case of the manifest of `solution`, panicking if it fails.
+/// Used by the tests generated by `example_tests!`.
+#[track_caller]
+pub fn check(solution: &Solution, file: &str, part: u8, expected: &str) {
+    let example = Example {
+        file: file.into(),
+        part,
+        expected: expected.into(),
+        slow: false,
+    };
+
+    match run_example(solution, &example) {
+        Outcome::Pass => {}
+        Outcome::Fail { actual } => panic!(
+            "{file} part {part}: expected {expected}, got {}",
+            actual.as_deref().unwrap_or("no answer")
+        ),
+        Outcome::Error(e) => panic!("{file} part {part}: {e}"),
+    }
+}
+
+/// Runs the cases from the manifest of `solution` and prints one line per case.
+/// Slow cases are skipped unless `include_slow` is set. Returns whether all cases passed.
+pub fn run(solution: &Solution, include_slow: bool) -> Result<bool, String> {
+    let manifest = Manifest::read(solution.scope, solution.day)?;
+
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+
+    for example in &manifest.examples {
+        let name = format!("{} part {}", example.file, example.part);
+
+        if example.slow && !include_slow {
+            skipped += 1;
+            println!("{name}: skipped (slow)");
+            continue;
+        }
+
+        let timer = Instant::now();
+        let outcome = run_example(solution, example);
+        let elapsed = timer.elapsed();
+
+        match outcome {
+            Outcome::Pass => {
+                passed += 1;
+                println!(
+                    "{name}: {ANSI_GREEN}pass{ANSI_RESET} ({ANSI_BOLD}{}{ANSI_RESET}, {elapsed:.1?})",
+                    example.expected
+                );
+            }
+            Outcome::Fail { actual } => {
+                failed += 1;
+                let actual = actual.as_deref().unwrap_or("no answer");
+                println!(
+                    "{name}: {ANSI_RED}fail{ANSI_RESET} (expected {}, got {actual})",
+                    example.expected
+                );
+            }
+            Outcome::Error(e) => {
+                failed += 1;
+                println!("{name}: {ANSI_RED}error{ANSI_RESET} ({e})");
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Examples:{ANSI_RESET} {passed} passed, {failed} failed, {skipped} skipped"
+    );
+
+    Ok(failed == 0)
+}
+
+/// An example file of a day, either `<day>.txt` or a numbered variant like `<day>-2.txt`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExampleFile {
+    Main,
+    Numbered(u8),
+}
+
+impl ExampleFile {
+    pub fn name(self, day: Day) -> String {
+        match self {
+            ExampleFile::Main => format!("{day}.txt"),
+            ExampleFile::Numbered(n) => format!("{day}-{n}.txt"),
+        }
+    }
+
+    /// Matches the file name of an example file of `day`.
+    fn from_name(day: Day, name: &str) -> Option<Self> {
+        let rest = name.strip_prefix(&day.to_string())?.strip_suffix(".txt")?;
+        match rest.strip_prefix('-') {
+            _ if rest.is_empty() => Some(ExampleFile::Main),
+            Some(n) => n.parse().ok().map(ExampleFile::Numbered),
+            None => None,
+        }
+    }
+
+    fn read(self, scope: Scope, day: Day) -> String {
+        match self {
+            ExampleFile::Main => scope.read_file("examples", day),
+            ExampleFile::Numbered(n) => scope.read_file_part("examples", day, n),
+        }
+    }
+}
+
+/// Which example files `cargo solve <day> --example` runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Selection {
+    #[default]
+    All,
+    /// The numbered variant `<day>-<n>.txt`.
+    Numbered(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Selection::All),
+            s => s.parse().map(Selection::Numbered).map_err(|_| {
+                format!("expected `all` or the number of an example file, got `{s}`.")
+            }),
+        }
+    }
+}
+
+/// Parses the value of `--part`, which picks a single part to run over the example files.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("expected part `1` or `2`, got `{s}`.")),
+    }
+}
+
+/// The example files of `day` that exist, ordered by number.
+pub(crate) fn example_files(scope: Scope, day: Day) -> Vec<ExampleFile> {
+    let mut files: Vec<ExampleFile> = fs::read_dir(scope.data_path("examples", ""))
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| ExampleFile::from_name(day, entry.file_name().to_str()?))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    files.sort_unstable();
+    files
+}
+
+/// Runs `part` of `solution`, or both parts if it is `None`, over the selected example files and
+/// prints a table of their answers and timings. Timings include the parse stage of the solution,
+/// if it has one.
+pub fn run_files(
+    solution: &Solution,
+    selection: Selection,
+    part: Option<u8>,
+) -> Result<(), String> {
+    let (scope, day) = (solution.scope, solution.day);
+
+    let files = match selection {
+        Selection::All => example_files(scope, day),
+        Selection::Numbered(n) => vec![ExampleFile::Numbered(n)],
+    };
+
+    if let Some(missing) = files
+        .iter()
+        .map(|file| scope.data_path("examples", &file.name(day)))
+        .find(|path| !Path::new(path).exists())
+    {
+        return Err(format!("could not find example file \"{missing}\"."));
+    }
+
+    if files.is_empty() {
+        return Err(format!("no example files found for day {day}."));
+    }
+
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+
+    let rows: Vec<Vec<Cell>> = files
+        .iter()
+        .map(|&file| {
+            let input = file.read(scope, day);
+            let answers = parts.iter().map(|&part| run_cell(solution, part, &input));
+            [Cell::plain(file.name(day))]
+                .into_iter()
+                .chain(answers)
+                .collect()
+        })
+        .collect();
+
+    let header: Vec<Cell> = [Cell::plain("File".into())]
+        .into_iter()
+        .chain(parts.iter().map(|part| Cell::plain(format!("Part {part}"))))
+        .collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[i].text.chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    println!("{ANSI_BOLD}Day {day} examples{ANSI_RESET}");
+    println!();
+
+    for row in [&header].into_iter().chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| {
+                let text = format!("{:<width$}", cell.text);
+                if cell.failed {
+                    format!("{ANSI_RED}{text}{ANSI_RESET}")
+                } else {
+                    text
+                }
+            })
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+
+    Ok(())
+}
+
+/// A cell of the table printed by [`run_files`].
+struct Cell {
+    text: String,
+    failed: bool,
+}
+
+impl Cell {
+    fn plain(text: String) -> Self {
+        Cell {
+            text,
+            failed: false,
+        }
+    }
+}
+
+fn run_cell(solution: &Solution, part: u8, input: &str) -> Cell {
+    let timer = Instant::now();
+    let result = panic::catch_unwind(|| (solution.answer)(part, input));
+    let elapsed = timer.elapsed();
+
+    match result {
+        // multiline answers are shown on a single line to keep the table intact.
+        Ok(Ok(Some(answer))) => {
+            Cell::plain(format!("{} ({elapsed:.1?})", answer.replace('\n', "⏎")))
+        }
+        Ok(Ok(None)) => Cell::plain("✖".into()),
+        Ok(Err(_)) => Cell {
+            text: format!("failed ({elapsed:.1?})"),
+            failed: true,
+        },
+        Err(_) => Cell {
+            text: "panicked".into(),
+            failed: true,
+        },
+    }
+}
+
+/// Generates one test per case of the example manifest of a solution, named after the example
+/// file and part, e.g. `example_13_1_part_2`, so that a single case can be run with `cargo test`.
+///
+/// The tests are generated by `build.rs`. Cases tagged `slow` are generated as ignored tests,
+/// run them with `cargo test -- --ignored`.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/examples/",
+            env!("CARGO_CRATE_NAME"),
+            ".rs"
+        ));
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_part, Example, ExampleFile, Manifest, Selection};
+    use crate::day;
+
+    #[test]
+    fn matches_example_files() {
+        let day = day!(13);
+        assert_eq!(
+            ExampleFile::from_name(day, "13.txt"),
+            Some(ExampleFile::Main)
+        );
+        assert_eq!(
+            ExampleFile::from_name(day, "13-5.txt"),
+            Some(ExampleFile::Numbered(5))
+        );
+        assert_eq!(ExampleFile::from_name(day, "13.manifest"), None);
+        assert_eq!(ExampleFile::from_name(day, "13-x.txt"), None);
+        assert_eq!(ExampleFile::from_name(day, "13x.txt"), None);
+        assert_eq!(ExampleFile::from_name(day, "14.txt"), None);
+        assert_eq!(ExampleFile::Numbered(2).name(day), "13-2.txt");
+    }
+
+    #[test]
+    fn parses_parts() {
+        assert_eq!(parse_part("1"), Ok(1));
+        assert_eq!(parse_part("2"), Ok(2));
+        assert_eq!(
+            parse_part("3"),
+            Err("expected part `1` or `2`, got `3`.".into())
+        );
+        assert_eq!(
+            parse_part("x"),
+            Err("expected part `1` or `2`, got `x`.".into())
+        );
+    }
+
+    #[test]
+    fn parses_selection() {
+        assert_eq!("all".parse(), Ok(Selection::All));
+        assert_eq!("3".parse(), Ok(Selection::Numbered(3)));
+        assert!("first".parse::<Selection>().is_err());
+    }
+}
@@ -0,0 +1,159 @@
+use std::{env, fs, path::Path};
+
+use crate::template::config::Config;
+use crate::template::{Day, Year};
+
+/// Environment variable that replaces the `data` directory, e.g. to run against alternate inputs.
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Where the files belonging to a year are stored.
+///
+/// The configured year uses the flat layout, other years are stored in year-scoped paths, so that
+/// several years can live in the same repository. Directories can be changed in `aoc.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// `src/bin/<day>.rs`, `data/inputs/<day>.txt` etc.
+    Flat,
+    /// `src/bin/<year>-<day>.rs`, `data/<year>/inputs/<day>.txt` etc.
+    Year(Year),
+}
+
+impl Scope {
+    /// The scope new files for `year` are created in.
+    pub fn for_year(year: Year) -> Self {
+        if Year::configured() == Some(year) {
+            Scope::Flat
+        } else {
+            Scope::Year(year)
+        }
+    }
+
+    /// The scope of an existing solution. Year-scoped solutions are found even if their year
+    /// is the configured one.
+    pub fn resolve(year: Year, day: Day) -> Self {
+        let scoped = Scope::Year(year);
+        if Path::new(&scoped.bin_path(day)).exists() {
+            scoped
+        } else {
+            Self::for_year(year)
+        }
+    }
+
+    /// The year of this scope. For the flat layout, this is the configured year.
+    pub fn year(self) -> Option<Year> {
+        match self {
+            Scope::Flat => Year::configured(),
+            Scope::Year(year) => Some(year),
+        }
+    }
+
+    /// The data directory of this scope, below the configured data directory or the one set in
+    /// `AOC_DATA_DIR`.
+    pub fn data_dir(self) -> String {
+        let root = env::var(DATA_DIR_ENV)
+            .ok()
+            .filter(|dir| !dir.is_empty())
+            .unwrap_or_else(|| Config::get().paths.data.clone());
+
+        match self {
+            Scope::Flat => root,
+            Scope::Year(year) => format!("{root}/{year}"),
+        }
+    }
+
+    /// Path of a file in one of the data folders, e.g. `data/inputs/01.txt`.
+    pub fn data_path(self, folder: &str, file_name: &str) -> String {
+        format!("{}/{folder}/{file_name}", self.data_dir())
+    }
+
+    pub fn input_path(self, day: Day) -> String {
+        self.data_path("inputs", &format!("{day}.txt"))
+    }
+
+    pub fn example_path(self, day: Day) -> String {
+        self.data_path("examples", &format!("{day}.txt"))
+    }
+
+    pub fn puzzle_path(self, day: Day) -> String {
+        self.data_path("puzzles", &format!("{day}.md"))
+    }
+
+    pub fn timings_path(self) -> String {
+        // joining keeps an absolute `AOC_DATA_DIR` intact.
+        Path::new(".")
+            .join(self.data_dir())
+            .join(&Config::get().paths.timings)
+            .display()
+            .to_string()
+    }
+
+    /// Name of the solution binary of `day`, e.g. `01` or `2021-01`.
+    pub fn bin_name(self, day: Day) -> String {
+        match self {
+            Scope::Flat => day.to_string(),
+            Scope::Year(year) => format!("{year}-{day}"),
+        }
+    }
+
+    pub fn bin_path(self, day: Day) -> String {
+        Path::new(".")
+            .join(&Config::get().paths.bins)
+            .join(format!("{}.rs", self.bin_name(day)))
+            .display()
+            .to_string()
+    }
+
+    /// Marker that surrounds the benchmark table of this scope in the readme.
+    pub fn readme_marker(self) -> String {
+        let marker = &Config::get().readme.marker;
+
+        match (self, marker.strip_suffix("--->")) {
+            (Scope::Flat, _) => marker.clone(),
+            (Scope::Year(year), Some(start)) => format!("{start}{year} --->"),
+            (Scope::Year(year), None) => format!("{marker} {year}"),
+        }
+    }
+
+    /// Reads a text file of this scope to a string.
+    #[must_use]
+    pub fn read_file(self, folder: &str, day: Day) -> String {
+        let cwd = env::current_dir().unwrap();
+        let filepath = cwd.join(self.data_path(folder, &format!("{day}.txt")));
+        let f = fs::read_to_string(&filepath);
+        f.unwrap_or_else(|e| panic!("could not open input file {filepath:?}: {e}"))
+    }
+
+    /// Reads a text file of this scope to string, appending a part suffix. E.g. like `01-2.txt`.
+    #[must_use]
+    pub fn read_file_part(self, folder: &str, day: Day, part: u8) -> String {
+        let cwd = env::current_dir().unwrap();
+        let filepath = cwd.join(self.data_path(folder, &format!("{day}-{part}.txt")));
+        let f = fs::read_to_string(&filepath);
+        f.unwrap_or_else(|e| panic!("could not open input file {filepath:?}: {e}"))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Scope;
+    use crate::{day, year};
+
+    #[test]
+    fn flat_paths() {
+        assert_eq!(Scope::Flat.input_path(day!(1)), "data/inputs/01.txt");
+        assert_eq!(Scope::Flat.bin_name(day!(1)), "01");
+        assert_eq!(Scope::Flat.bin_path(day!(1)), "./src/bin/01.rs");
+        assert_eq!(Scope::Flat.timings_path(), "./data/timings.json");
+    }
+
+    #[test]
+    fn year_scoped_paths() {
+        let scope = Scope::Year(year!(2021));
+        assert_eq!(scope.input_path(day!(1)), "data/2021/inputs/01.txt");
+        assert_eq!(scope.puzzle_path(day!(1)), "data/2021/puzzles/01.md");
+        assert_eq!(scope.bin_name(day!(1)), "2021-01");
+        assert_eq!(scope.bin_path(day!(1)), "./src/bin/2021-01.rs");
+        assert_eq!(scope.timings_path(), "./data/2021/timings.json");
+        assert_eq!(scope.readme_marker(), "<!--- benchmarking table 2021 --->");
+    }
+}
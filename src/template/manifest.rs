@@ -0,0 +1,221 @@
+/// Module that parses the manifests of example cases, `data/examples/<day>.manifest`.
+///
+/// `build.rs` includes this file as well, to generate a test per case, so it must not depend on
+/// the rest of the crate.
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A single example case: the answer a part should produce for an example file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    /// File name in the examples folder, e.g. `13-1.txt`.
+    pub file: String,
+    pub part: u8,
+    pub expected: String,
+    /// Slow cases are only run when asked for.
+    pub slow: bool,
+}
+
+impl Display for Example {
+    /// Formats the case as a manifest line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.file, self.part, self.expected)?;
+        if self.slow {
+            write!(f, " slow")?;
+        }
+        Ok(())
+    }
+}
+
+/// The example cases of a day, stored in `data/examples/<day>.manifest`.
+///
+/// Every non-empty line that does not start with `#` holds one case:
+///
+/// ```text
+/// # file    part  expected  [slow]
+/// 13.txt    1     295
+/// 13-1.txt  2     3417
+/// 15.txt    2     175594    slow
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub examples: Vec<Example>,
+}
+
+impl FromStr for Manifest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let examples = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(i, line)| {
+                parse_example(line).ok_or_else(|| {
+                    format!(
+                        "invalid case on line {}: expected `<file> <part> <expected> [slow]`.",
+                        i + 1
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Manifest { examples })
+    }
+}
+
+fn parse_example(line: &str) -> Option<Example> {
+    let mut tokens = line.split_whitespace();
+
+    let file = tokens.next()?.to_string();
+    let part = tokens
+        .next()?
+        .parse()
+        .ok()
+        .filter(|part| matches!(part, 1 | 2))?;
+    let expected = tokens.next()?.to_string();
+
+    let slow = match tokens.next() {
+        None => false,
+        Some("slow") => true,
+        Some(_) => return None,
+    };
+
+    tokens.next().is_none().then_some(Example {
+        file,
+        part,
+        expected,
+        slow,
+    })
+}
+
+impl Manifest {
+    /// Generates one test per case, which `example_tests!` includes in the tests of a solution.
+    /// Slow cases are ignored tests.
+    pub fn to_tests(&self) -> String {
+        let mut names: Vec<String> = vec![];
+        let mut out = String::new();
+
+        for example in &self.examples {
+            let name = test_name(example, &names);
+
+            if example.slow {
+                out += "#[ignore = \"slow\"]\n";
+            }
+            out += &format!(
+                "#[test]\nfn {name}() {{\n    advent_of_code::template::examples::check(&SOLUTION, {:?}, {}, {:?});\n}}\n\n",
+                example.file, example.part, example.expected
+            );
+
+            names.push(name);
+        }
+
+        out
+    }
+}
+
+/// A test that fails with `error`, generated if the manifest can not be read.
+#[allow(dead_code)] // only used by `build.rs`.
+pub fn failing_test(error: &str) -> String {
+    format!("#[test]\nfn example_manifest() {{\n    panic!(\"{{}}\", {error:?});\n}}\n")
+}
+
+/// e.g. `example_13_1_part_2` for `13-1.txt 2`. A case that repeats a file and part is numbered.
+fn test_name(example: &Example, taken: &[String]) -> String {
+    let file = example.file.strip_suffix(".txt").unwrap_or(&example.file);
+    let file: String = file
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    let name = format!("example_{file}_part_{}", example.part);
+
+    (1..)
+        .map(|i| match i {
+            1 => name.clone(),
+            i => format!("{name}_{i}"),
+        })
+        .find(|name| !taken.contains(name))
+        .unwrap()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{failing_test, Example, Manifest};
+
+    #[test]
+    fn parses_manifest() {
+        let manifest: Manifest = "# file part expected\n13.txt 1 295\n\n15-1.txt  2  2578  slow\n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            manifest.examples,
+            vec![
+                Example {
+                    file: "13.txt".into(),
+                    part: 1,
+                    expected: "295".into(),
+                    slow: false,
+                },
+                Example {
+                    file: "15-1.txt".into(),
+                    part: 2,
+                    expected: "2578".into(),
+                    slow: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_manifest_lines() {
+        let example = Example {
+            file: "15-1.txt".into(),
+            part: 2,
+            expected: "2578".into(),
+            slow: true,
+        };
+        assert_eq!(example.to_string(), "15-1.txt 2 2578 slow");
+        assert_eq!(
+            example.to_string().parse::<Manifest>().unwrap().examples,
+            vec![example]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_cases() {
+        assert!("13.txt 3 295".parse::<Manifest>().is_err());
+        assert!("13.txt 1".parse::<Manifest>().is_err());
+        assert!("13.txt 1 295 fast".parse::<Manifest>().is_err());
+        assert!("13.txt 1 295 slow extra".parse::<Manifest>().is_err());
+    }
+
+    #[test]
+    fn generates_a_test_per_case() {
+        let manifest: Manifest =
+            "13.txt 1 295\n13-1.txt 2 3417\n13-1.txt 2 3417\n15.txt 2 175594 slow\n"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            manifest.to_tests(),
+            "#[test]\n\
+            fn example_13_part_1() {\n    advent_of_code::template::examples::check(&SOLUTION, \"13.txt\", 1, \"295\");\n}\n\n\
+            #[test]\n\
+            fn example_13_1_part_2() {\n    advent_of_code::template::examples::check(&SOLUTION, \"13-1.txt\", 2, \"3417\");\n}\n\n\
+            #[test]\n\
+            fn example_13_1_part_2_2() {\n    advent_of_code::template::examples::check(&SOLUTION, \"13-1.txt\", 2, \"3417\");\n}\n\n\
+            #[ignore = \"slow\"]\n\
+            #[test]\n\
+            fn example_15_part_2() {\n    advent_of_code::template::examples::check(&SOLUTION, \"15.txt\", 2, \"175594\");\n}\n\n"
+        );
+        assert_eq!(Manifest::default().to_tests(), "");
+        assert_eq!(
+            failing_test("13.manifest: invalid case on line 1."),
+            "#[test]\nfn example_manifest() {\n    panic!(\"{}\", \"13.manifest: invalid case on line 1.\");\n}\n"
+        );
+    }
+}
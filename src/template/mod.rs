@@ -1,68 +1,142 @@
-use std::{env, fs};
-
 pub mod aoc_cli;
+#[cfg(feature = "client")]
+pub mod client;
 pub mod commands;
+pub mod config;
+pub mod examples;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod stats;
 
+pub use compare::parse_threshold;
 pub use day::*;
+pub use layout::*;
+pub use run_multi::{Executor, Limits};
+pub use year::*;
 
+mod answers;
+mod compare;
 mod day;
+mod layout;
+mod manifest;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod status;
+mod submissions;
 mod timings;
+mod toml;
+mod watch;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    Scope::Flat.read_file(folder, day)
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    Scope::Flat.read_file_part(folder, day, part)
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY` and `SCOPE` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Optional parameters follow the day, in any order:
+///  - `1` or `2` allows you to only run a single part of the solution.
+///  - `parse = <fn>` adds a parse stage, whose output is passed to both parts by reference.
+///    It returns `Option<T>` or `anyhow::Result<T>`, like the parts do.
+///  - `year = <year>` is passed by solutions of years other than the one in `AOC_YEAR`, e.g. `solution!(1, year = 2021)`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    (@args [$day:expr] [$scope:expr] [$($parse:ident)?] [$($parts:tt)*]) => {
+        $crate::solution!(@impl $scope, $day, [$($parse)?], $($parts)*);
+    };
+    (@args [$day:expr] [$scope:expr] [$($parse:ident)?] [$($parts:tt)*], 1 $($rest:tt)*) => {
+        $crate::solution!(@args [$day] [$scope] [$($parse)?] [[part_one, 1]] $($rest)*);
+    };
+    (@args [$day:expr] [$scope:expr] [$($parse:ident)?] [$($parts:tt)*], 2 $($rest:tt)*) => {
+        $crate::solution!(@args [$day] [$scope] [$($parse)?] [[part_two, 2]] $($rest)*);
+    };
+    (@args [$day:expr] [$scope:expr] [$($parse:ident)?] [$($parts:tt)*], parse = $new_parse:ident $($rest:tt)*) => {
+        $crate::solution!(@args [$day] [$scope] [$new_parse] [$($parts)*] $($rest)*);
+    };
+    (@args [$day:expr] [$scope:expr] [$($parse:ident)?] [$($parts:tt)*], year = $year:literal $($rest:tt)*) => {
+        $crate::solution!(@args [$day] [$crate::template::Scope::Year($crate::year!($year))] [$($parse)?] [$($parts)*] $($rest)*);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+
+    (@run [] $( [$func:expr, $part:expr] )*) => {
+        |input, options| {
+            use $crate::template::runner::*;
+            vec![$( run_part($func, input, SCOPE, DAY, $part, options), )*]
+        }
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@run [$parse:ident] $( [$func:expr, $part:expr] )*) => {
+        |input, options| {
+            use $crate::template::runner::*;
+            let (parsed, report) = run_parse($parse, input, DAY, options);
+            let mut reports = vec![report];
+            match parsed {
+                // the closure lets parts take e.g. `&[T]` for a parsed `Vec<T>`.
+                Some(parsed) => reports.extend([$( run_part(|parsed| $func(parsed), &parsed, SCOPE, DAY, $part, options), )*]),
+                None => reports.extend([$( fail_part(DAY, $part, options), )*]),
+            }
+            reports
+        }
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@answer [] $( [$func:expr, $part:expr] )*) => {
+        |part, input| {
+            use $crate::template::runner::PartResult;
+            $( if part == $part {
+                return $func(input).answer();
+            } )*
+            Ok(None)
+        }
+    };
+    (@answer [$parse:ident] $( [$func:expr, $part:expr] )*) => {
+        |part, input| {
+            use $crate::template::runner::{ParseResult, PartResult};
+            let parsed = $parse(input).into_parsed()?;
+            $( if part == $part {
+                return $func(&parsed).answer();
+            } )*
+            Ok(None)
+        }
+    };
+
+    (@impl $scope:expr, $day:expr, [$($parse:ident)?], $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// Where the files of this solution are stored.
+        #[allow(dead_code)]
+        const SCOPE: $crate::template::Scope = $scope;
+
+        /// Entry point used to run this day, both by `main` and by the solution registry.
+        pub const SOLUTION: $crate::template::registry::Solution = $crate::template::registry::Solution {
+            scope: SCOPE,
+            day: DAY,
+            run: $crate::solution!(@run [$($parse)?] $( [$func, $part] )*),
+            answer: $crate::solution!(@answer [$($parse)?] $( [$func, $part] )*),
+        };
 
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let options = $crate::template::runner::RunOptions::from_env();
+            let input = options.read_input(SCOPE, DAY);
+            (SOLUTION.run)(&input, &options);
         }
     };
+
+    ($day:expr $(, $($args:tt)*)?) => {
+        $crate::solution!(@args [$day] [$crate::template::Scope::Flat] [] [[part_one, 1] [part_two, 2]] $(, $($args)*)?);
+    };
 }
@@ -0,0 +1,290 @@
+/// Module that extracts information from the puzzle descriptions stored in `data/puzzles`.
+use std::fs;
+
+use crate::template::{Day, Scope};
+
+/// Heading that separates the two parts of a puzzle description.
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// An example input found in a puzzle description, with the answers that are mentioned after it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExampleBlock {
+    pub input: String,
+    /// Answers to part one and two.
+    pub answers: [Option<String>; 2],
+}
+
+impl ExampleBlock {
+    /// Line lengths of the block. Consecutive blocks of the same shape, like the states of
+    /// a grid after every step, illustrate the same example.
+    fn shape(&self) -> Vec<usize> {
+        self.input.lines().map(str::len).collect()
+    }
+}
+
+/// A puzzle description, as Markdown.
+pub struct Puzzle {
+    pub(crate) markdown: String,
+}
+
+impl Puzzle {
+    /// Reads the stored description of `day`, if it was downloaded.
+    pub fn read(scope: Scope, day: Day) -> Option<Self> {
+        fs::read_to_string(scope.puzzle_path(day))
+            .ok()
+            .map(|markdown| Puzzle { markdown })
+    }
+
+    /// The title of the puzzle, e.g. `Report Repair` for `--- Day 1: Report Repair ---`.
+    pub fn title(&self) -> Option<&str> {
+        self.markdown.lines().find_map(|line| {
+            let (_, title) = line.split_once("--- Day ")?;
+            let (_, title) = title.split_once(": ")?;
+            Some(title.strip_suffix("---")?.trim())
+        })
+    }
+
+    /// The text describing `part`. Part two is only included once part one was solved.
+    fn part(&self, part: u8) -> Option<&str> {
+        let (one, two) = match self.markdown.split_once(PART_TWO_HEADING) {
+            Some((one, two)) => (one, Some(two)),
+            None => (self.markdown.as_str(), None),
+        };
+
+        match part {
+            1 => Some(one),
+            2 => two,
+            _ => None,
+        }
+    }
+
+    /// The answer to the example of `part`. The puzzle text highlights it as emphasized code,
+    /// and it is usually the last highlighted value before the question.
+    pub fn example_answer(&self, part: u8) -> Option<String> {
+        emphasized_code(self.part(part)?).pop()
+    }
+
+    /// The code blocks of the description that look like example inputs, in order.
+    ///
+    /// An emphasized value after a block is taken as the answer to its example, the last one
+    /// before the next block wins. Part two usually refers to the first example again.
+    pub fn example_blocks(&self) -> Vec<ExampleBlock> {
+        let mut blocks: Vec<ExampleBlock> = vec![];
+        let mut current: Option<usize> = None;
+        let mut part = 1;
+        let mut block: Option<Vec<&str>> = None;
+
+        for line in self.markdown.lines() {
+            if let Some(lines) = &mut block {
+                let end = line.trim_start().starts_with("```") || line.contains("</code></pre>");
+                if !end {
+                    lines.push(line);
+                    continue;
+                }
+
+                if let Some((last, _)) = line.split_once("</code></pre>") {
+                    lines.push(last);
+                }
+                let input = block_input(lines);
+                block = None;
+
+                if !input.trim().is_empty() {
+                    current = Some(add_block(&mut blocks, current, input));
+                }
+                continue;
+            }
+
+            if line.trim_start().starts_with("```") {
+                block = Some(vec![]);
+                continue;
+            }
+
+            if let Some((_, first)) = line.split_once("<pre><code>") {
+                if let Some((input, _)) = first.split_once("</code></pre>") {
+                    current = Some(add_block(&mut blocks, current, block_input(&[input])));
+                } else {
+                    block = Some(vec![first]);
+                }
+                continue;
+            }
+
+            if line.contains(PART_TWO_HEADING) {
+                part = 2;
+                current = (!blocks.is_empty()).then_some(0);
+                continue;
+            }
+
+            if let (Some(current), Some(answer)) = (current, emphasized_code(line).pop()) {
+                blocks[current].answers[part - 1] = Some(answer);
+            }
+        }
+
+        blocks
+    }
+}
+
+/// Joins the lines of a code block, decoding the entities of HTML blocks.
+fn block_input(lines: &[&str]) -> String {
+    let input = lines
+        .iter()
+        .map(|line| {
+            line.replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&#39;", "'")
+                .replace("&amp;", "&")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    input.trim_end().to_string() + "\n"
+}
+
+/// Adds a block, unless it repeats or continues the example of a previous block.
+/// Returns the index of the block that answers are attributed to.
+fn add_block(blocks: &mut Vec<ExampleBlock>, current: Option<usize>, input: String) -> usize {
+    if let Some(i) = blocks.iter().position(|block| block.input == input) {
+        return i;
+    }
+
+    let block = ExampleBlock {
+        input,
+        answers: [None, None],
+    };
+
+    match current {
+        Some(i) if block.input.lines().count() > 1 && blocks[i].shape() == block.shape() => i,
+        _ => {
+            blocks.push(block);
+            blocks.len() - 1
+        }
+    }
+}
+
+/// All values formatted as emphasized code, i.e. `` `*42*` `` or ``*`42`*``, outside of code blocks.
+fn emphasized_code(text: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut in_block = false;
+
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+        if in_block {
+            continue;
+        }
+
+        let mut found: Vec<(usize, &str)> = [("`*", "*`"), ("*`", "`*")]
+            .into_iter()
+            .flat_map(|(open, close)| delimited(line, open, close))
+            .collect();
+        found.sort_unstable_by_key(|(pos, _)| *pos);
+
+        values.extend(found.into_iter().map(|(_, value)| value.to_string()));
+    }
+
+    values
+}
+
+/// Values between `open` and `close` with their position, skipping matches that span other markup.
+fn delimited<'a>(line: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut values = vec![];
+    let mut pos = 0;
+
+    while let Some(start) = line[pos..].find(open).map(|i| pos + i + open.len()) {
+        let Some(end) = line[start..].find(close).map(|i| start + i) else {
+            break;
+        };
+
+        let value = &line[start..end];
+        if !value.is_empty() && value.trim() == value && !value.contains(['`', '*']) {
+            values.push((start, value));
+        }
+        pos = start;
+    }
+
+    values
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ExampleBlock, Puzzle};
+
+    const PUZZLE: &str = "## --- Day 1: Report Repair ---\n\n\
+        In this list, the two entries that sum to `*2020*` are `1721` and `299`. \
+        Multiplying them together produces `1721 * 299 = 514579`, so the correct answer is `*514579*`.\n\n\
+        ```\n1721\n`*979*`\n```\n\n\
+        *Find the two entries that sum to `2020`; what do you get if you multiply them together?*\n\n\
+        ## --- Part Two ---\n\n\
+        In your expense report, the product of them is *`241861950`*.\n";
+
+    fn puzzle(markdown: &str) -> Puzzle {
+        Puzzle {
+            markdown: markdown.into(),
+        }
+    }
+
+    #[test]
+    fn extracts_title() {
+        assert_eq!(puzzle(PUZZLE).title(), Some("Report Repair"));
+        assert_eq!(
+            puzzle("\\--- Day 8: Handheld Halting ---\n").title(),
+            Some("Handheld Halting")
+        );
+        assert_eq!(puzzle("no title").title(), None);
+    }
+
+    #[test]
+    fn extracts_example_answers() {
+        let puzzle = puzzle(PUZZLE);
+        assert_eq!(puzzle.example_answer(1), Some("514579".into()));
+        assert_eq!(puzzle.example_answer(2), Some("241861950".into()));
+    }
+
+    #[test]
+    fn handles_unsolved_part_one() {
+        let puzzle = puzzle(PUZZLE.split("## --- Part Two").next().unwrap());
+        assert_eq!(puzzle.example_answer(2), None);
+    }
+
+    #[test]
+    fn extracts_example_blocks() {
+        let blocks = puzzle(PUZZLE).example_blocks();
+        assert_eq!(
+            blocks,
+            vec![ExampleBlock {
+                input: "1721\n`*979*`\n".into(),
+                answers: [None, Some("241861950".into())],
+            }]
+        );
+    }
+
+    #[test]
+    fn groups_steps_of_an_example() {
+        let markdown = "<pre><code>L.L\nLLL\n</code></pre>\n\
+            After one round:\n\n\
+            ```\n#.#\n###\n```\n\n\
+            Then `*7*` seats are occupied.\n\n\
+            A larger example:\n\n\
+            <pre><code>a &lt; b</code></pre>\n\n\
+            It yields `*1*`.\n\n\
+            ## --- Part Two ---\n\n\
+            Now `*3*` seats are occupied.\n";
+
+        let blocks = puzzle(markdown).example_blocks();
+        assert_eq!(
+            blocks,
+            vec![
+                ExampleBlock {
+                    input: "L.L\nLLL\n".into(),
+                    answers: [Some("7".into()), Some("3".into())],
+                },
+                ExampleBlock {
+                    input: "a < b\n".into(),
+                    answers: [Some("1".into()), None],
+                },
+            ]
+        );
+    }
+}
@@ -2,10 +2,10 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::timings::Timings;
-use crate::template::Day;
-
-static MARKER: &str = "<!--- benchmarking table --->";
+use crate::template::config::Config;
+use crate::template::runner::FAILED;
+use crate::template::timings::{format_bytes, PartTiming, Timing, Timings};
+use crate::template::Scope;
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -25,13 +25,8 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,76 +47,145 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// The highest peak heap usage of any stage of a day.
+fn peak_bytes(timing: &Timing) -> Option<u64> {
+    [&timing.parse, &timing.part_1, &timing.part_2]
+        .into_iter()
+        .filter_map(|part| part.as_ref()?.memory)
+        .map(|memory| memory.peak_bytes)
+        .max()
+}
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+fn construct_table(prefix: &str, scope: Scope, timings: Timings, total_millis: f64) -> String {
+    let header = match scope {
+        Scope::Flat => format!("{prefix} Benchmarks"),
+        Scope::Year(year) => format!("{prefix} {year} Benchmarks"),
+    };
+    let marker = scope.readme_marker();
+
+    // the parse column is only shown once a solution has a parse stage.
+    let has_parse = timings.data.iter().any(|t| t.parse.is_some());
+    // the memory column is only shown once heap usage was measured with `cargo time --memory`.
+    let has_memory = timings.data.iter().any(|t| peak_bytes(t).is_some());
+
+    let mut lines: Vec<String> = vec![marker.clone(), header, String::new()];
+
+    let mut columns = vec!["Day"];
+    if has_parse {
+        columns.push("Parse");
+    }
+    columns.extend(["Part 1", "Part 2"]);
+    if has_memory {
+        columns.push("Memory");
+    }
+
+    lines.push(format!("| {} |", columns.join(" | ")));
+    lines.push(format!(
+        "|{} :---:  |",
+        " :---: |".repeat(columns.len() - 1)
+    ));
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = scope.bin_path(timing.day);
+        let cell = |part: u8, part_timing: Option<&PartTiming>| match part_timing {
+            Some(p) => p.format_duration(),
+            None if timing.is_failed(part) => FAILED.into(),
+            None => timing.aborted.map_or("-".into(), |abort| abort.to_string()),
+        };
+        let parse = if has_parse {
+            format!(" `{}` |", cell(0, timing.parse.as_ref()))
+        } else {
+            String::new()
+        };
+        let memory = if has_memory {
+            let peak = peak_bytes(&timing).map_or("-".into(), format_bytes);
+            format!(" `{peak}` |")
+        } else {
+            String::new()
+        };
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) |{} `{}` | `{}` |{}",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            parse,
+            cell(1, timing.part_1.as_ref()),
+            cell(2, timing.part_2.as_ref()),
+            memory,
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    scope: Scope,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let positions = locate_table(s, &scope.readme_marker())?;
+    let table = construct_table("##", scope, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
+/// Updates the benchmark table of `scope` in the readme.
+pub fn update(scope: Scope, timings: Timings) -> Result<(), Error> {
+    let path = &Config::get().readme.path;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, scope, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::update_content;
+    use crate::{
+        day,
+        template::{
+            stats::MemoryStats,
+            timings::{Abort, PartTiming, Timing, Timings},
+            Scope,
+        },
+        year,
+    };
+
+    const MARKER: &str = "<!--- benchmarking table --->";
+
+    fn part(millis: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos: millis * 1_000_000_f64,
+            samples: Some(10),
+            memory: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    part_1: part(10.0),
+                    part_2: part(20.0),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    part_1: part(30.0),
+                    part_2: part(40.0),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    part_1: part(40.0),
+                    part_2: part(50.0),
                     total_nanos: 9e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -131,28 +195,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Scope::Flat, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Scope::Flat, get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Scope::Flat, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Scope::Flat, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Scope::Flat, get_mock_timings(), 190.0).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +224,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, Scope::Flat, get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +233,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +244,57 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_year_scoped_benchmarks() {
+        let scope = Scope::Year(year!(2021));
+        let marker = "<!--- benchmarking table 2021 --->";
+        let mut s = format!("{MARKER}\n{MARKER}\n{marker}\n{marker}");
+        update_content(&mut s, scope, get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches("## Benchmarks").count(), 0);
+        assert_eq!(s.matches("## 2021 Benchmarks").count(), 1);
+        assert!(s.starts_with(&format!("{MARKER}\n{MARKER}\n")));
+        assert!(s.contains("| [Day 1](./src/bin/2021-01.rs) | `10.0ms` | `20.0ms` |"));
+    }
+
+    #[test]
+    fn format_parse_column() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = part(5.0);
+        let mut s = format!("{MARKER}\n{MARKER}");
+        update_content(&mut s, Scope::Flat, timings, 195.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `-` | `10.0ms` | `20.0ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |"));
+    }
+
+    #[test]
+    fn format_aborted_days() {
+        let mut timings = get_mock_timings();
+        timings.data[2].part_2 = None;
+        timings.data[2].aborted = Some(Abort::Timeout);
+        let mut s = format!("{MARKER}\n{MARKER}");
+        update_content(&mut s, Scope::Flat, timings, 150.0).unwrap();
+        assert!(s.contains("| [Day 4](./src/bin/04.rs) | `40.0ms` | `TIMEOUT` |"));
+    }
+
+    #[test]
+    fn format_memory_column() {
+        let memory = |peak_bytes| MemoryStats {
+            total_bytes: 4096,
+            total_blocks: 8,
+            peak_bytes,
+            peak_blocks: 2,
+        };
+
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1.as_mut().unwrap().memory = Some(memory(512));
+        timings.data[0].part_2.as_mut().unwrap().memory = Some(memory(1536));
+        let mut s = format!("{MARKER}\n{MARKER}");
+        update_content(&mut s, Scope::Flat, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Memory |"));
+        assert!(s.contains("| :---: | :---: | :---: | :---:  |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` | `1.5KiB` |"));
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` | `-` |"));
+    }
 }
@@ -0,0 +1,37 @@
+/// Module that makes every solution in `src/bin` available to the main binary.
+///
+/// `build.rs` includes each `src/bin/<day>.rs` as a module of this crate, so days can be run without
+/// spawning a child process per day.
+use crate::template::report::PartReport;
+use crate::template::runner::RunOptions;
+use crate::template::{Day, Scope, Year};
+
+/// The entry point of a single day, created by the `solution!` macro.
+pub struct Solution {
+    pub scope: Scope,
+    pub day: Day,
+    /// Runs all implemented parts against `input` and returns their reports.
+    pub run: fn(&str, &RunOptions) -> Vec<PartReport>,
+    /// Solves a single part against `input`. Returns `Ok(None)` if the part has no answer,
+    /// or the error chain if it failed.
+    pub answer: fn(u8, &str) -> Result<Option<String>, String>,
+}
+
+/// One module per solution, e.g. `days::day01` or `days::y2021_day01` for year-scoped solutions.
+/// The library's own tests leave them out, every solution is tested by its bin.
+pub mod days {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+/// All registered solutions, ordered by day.
+pub fn all() -> &'static [Solution] {
+    days::SOLUTIONS
+}
+
+/// Looks up the solution for `day` of `year`, if there is one.
+pub fn find(year: Year, day: Day) -> Option<&'static Solution> {
+    let scope = Scope::resolve(year, day);
+    all()
+        .iter()
+        .find(|solution| solution.scope == scope && solution.day == day)
+}
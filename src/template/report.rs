@@ -0,0 +1,302 @@
+/// Machine-readable records emitted by solution binaries when run with `--format json`.
+use std::{collections::HashMap, fmt::Display, str::FromStr, time::Duration};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    stats::{optional_memory, MemoryStats, Stats},
+    Day,
+};
+
+/// Controls how solution results are written to stdout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Colored, human-oriented lines such as `Part 1: 42 (1.2ms)`.
+    #[default]
+    Human,
+    /// One JSON object per line and part.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unknown format `{s}`, expected `human` or `json`.")),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Human => f.write_str("human"),
+            Self::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// The `part` of reports that describe the parse stage of a solution instead of a part.
+pub const PARSE: u8 = 0;
+
+/// The outcome of running a single part of a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartReport {
+    pub day: Day,
+    /// The part, or [`PARSE`] for the shared parse stage.
+    pub part: u8,
+    /// `None` if the part is not implemented or failed.
+    pub answer: Option<String>,
+    /// The error chain if the part failed.
+    pub error: Option<String>,
+    pub stats: Stats,
+    /// Heap usage of the first run, if the solution was built with the `dhat-heap` feature.
+    pub memory: Option<MemoryStats>,
+}
+
+impl PartReport {
+    pub fn is_parse(&self) -> bool {
+        self.part == PARSE
+    }
+
+    /// Serializes the report to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self)
+            .stringify()
+            .expect("report should be serializable")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn nanos(duration: Duration) -> JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    JsonValue::Number(duration.as_nanos() as f64)
+}
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "day".into(),
+            JsonValue::Number(value.day.into_inner().into()),
+        );
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "error".into(),
+            match &value.error {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert("duration_nanos".into(), nanos(value.stats.mean));
+        map.insert("min_nanos".into(), nanos(value.stats.min));
+        map.insert("max_nanos".into(), nanos(value.stats.max));
+        map.insert("median_nanos".into(), nanos(value.stats.median));
+        map.insert("p95_nanos".into(), nanos(value.stats.p95));
+        map.insert("stddev_nanos".into(), nanos(value.stats.stddev));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "outliers".into(),
+            JsonValue::Number(value.stats.outliers as f64),
+        );
+        map.insert(
+            "memory".into(),
+            match &value.memory {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected report.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let day = Day::new(number("day")? as u8).ok_or("Expected report.day to be a Day.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = number("part")? as u8;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?
+            .cloned();
+
+        // older solution binaries do not report errors.
+        let error = json.get("error").and_then(|v| v.get::<String>()).cloned();
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = number("samples")? as u128;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let outliers = number("outliers")? as u128;
+
+        Ok(PartReport {
+            day,
+            part,
+            answer,
+            error,
+            stats: Stats {
+                samples,
+                outliers,
+                mean: duration("duration_nanos")?,
+                stddev: duration("stddev_nanos")?,
+                min: duration("min_nanos")?,
+                max: duration("max_nanos")?,
+                median: duration("median_nanos")?,
+                p95: duration("p95_nanos")?,
+            },
+            // solution binaries built without the `dhat-heap` feature do not report memory.
+            memory: optional_memory(json)?,
+        })
+    }
+}
+
+impl FromStr for PartReport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not a valid JSON line."))?;
+        PartReport::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{OutputFormat, PartReport};
+    use crate::{
+        day,
+        template::stats::{MemoryStats, Stats},
+    };
+
+    #[test]
+    fn parses_output_format() {
+        assert_eq!("json".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+        assert_eq!("human".parse::<OutputFormat>(), Ok(OutputFormat::Human));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = PartReport {
+            day: day!(3),
+            part: 2,
+            answer: Some("1234".into()),
+            error: None,
+            stats: Stats {
+                samples: 100,
+                outliers: 2,
+                mean: Duration::from_nanos(74_130),
+                stddev: Duration::from_nanos(1_200),
+                min: Duration::from_nanos(70_000),
+                max: Duration::from_nanos(90_000),
+                median: Duration::from_nanos(73_000),
+                p95: Duration::from_nanos(81_000),
+            },
+            memory: None,
+        };
+
+        let line = report.to_json_line();
+        assert!(!line.contains('\n'));
+        assert_eq!(line.parse::<PartReport>(), Ok(report));
+    }
+
+    #[test]
+    fn roundtrips_unsolved_reports() {
+        let report = PartReport {
+            day: day!(25),
+            part: 1,
+            answer: None,
+            error: None,
+            stats: Stats::single(Duration::from_nanos(10)),
+            memory: None,
+        };
+
+        assert_eq!(report.to_json_line().parse::<PartReport>(), Ok(report));
+    }
+
+    #[test]
+    fn roundtrips_failed_reports() {
+        let report = PartReport {
+            day: day!(8),
+            part: 1,
+            answer: None,
+            error: Some("invalid instruction `foo 1`\n\nCaused by:\n    unknown op".into()),
+            stats: Stats::single(Duration::from_nanos(10)),
+            memory: None,
+        };
+
+        let line = report.to_json_line();
+        assert!(!line.contains('\n'));
+        assert_eq!(line.parse::<PartReport>(), Ok(report));
+    }
+
+    #[test]
+    fn roundtrips_memory() {
+        let report = PartReport {
+            day: day!(14),
+            part: 2,
+            answer: Some("42".into()),
+            error: None,
+            stats: Stats::single(Duration::from_nanos(10)),
+            memory: Some(MemoryStats {
+                total_bytes: 4096,
+                total_blocks: 12,
+                peak_bytes: 1024,
+                peak_blocks: 3,
+            }),
+        };
+
+        assert_eq!(report.to_json_line().parse::<PartReport>(), Ok(report));
+
+        // reports of binaries built without the `dhat-heap` feature.
+        let line = r#"{"day":1,"part":1,"answer":"0","samples":1,"outliers":0,"duration_nanos":5,"stddev_nanos":0,"min_nanos":5,"max_nanos":5,"median_nanos":5,"p95_nanos":5}"#;
+        assert_eq!(line.parse::<PartReport>().unwrap().memory, None);
+    }
+
+    #[test]
+    fn rejects_malformed_reports() {
+        assert!(r#"{ "day": 1 }"#.parse::<PartReport>().is_err());
+        assert!("Part 1: 42 (1.0ms)".parse::<PartReport>().is_err());
+    }
+}
@@ -1,21 +1,120 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    fs, io, panic,
+    sync::{mpsc, Mutex},
+    thread,
+    time::Duration,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    registry,
+    report::{OutputFormat, PartReport, PARSE},
+    runner::RunOptions,
+    Day, Scope, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET,
+};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    timings::{
+        format_bytes, format_nanos, git_revision, parse_duration, unix_timestamp, Abort,
+        PartTiming, Timing, Timings,
+    },
 };
+use child_commands::{Profile, SolutionOutput};
+
+/// How `run_multi` executes the solutions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Executor {
+    /// Invoke every solution bin as a child process, running up to `jobs` of them at once.
+    Process { jobs: usize, limits: Limits },
+    /// Call the solutions from the solution registry, in the current process.
+    InProcess,
+}
+
+impl Executor {
+    /// Parses the number of solutions that are run at once, which must be at least one.
+    pub fn parse_jobs(s: &str) -> Result<usize, String> {
+        s.parse()
+            .ok()
+            .filter(|jobs| *jobs > 0)
+            .ok_or(format!("expected at least 1 job, got `{s}`."))
+    }
+}
+
+/// Resource limits of solutions that are run as child processes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock time after which a solution is killed.
+    pub timeout: Option<Duration>,
+    /// Maximum address space of a solution in bytes. Only enforced on unix.
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    /// Parses a timeout like `30s`, `500ms` or `30` (seconds).
+    pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+        parse_duration(s)
+            .filter(|nanos| *nanos > 0.0)
+            .map(|nanos| Duration::from_secs_f64(nanos / 1_000_000_000_f64))
+            .ok_or(format!("expected a duration like `30s`, got `{s}`."))
+    }
+
+    /// Parses a memory size like `512M`, `2G` or `1048576` (bytes). Suffixes are binary.
+    pub fn parse_memory(s: &str) -> Result<u64, String> {
+        let (number, unit) = match s.char_indices().last() {
+            Some((i, 'K' | 'k')) => (&s[..i], 1 << 10),
+            Some((i, 'M' | 'm')) => (&s[..i], 1 << 20),
+            Some((i, 'G' | 'g')) => (&s[..i], 1 << 30),
+            _ => (s, 1),
+        };
+
+        number
+            .parse::<u64>()
+            .ok()
+            .filter(|n| *n > 0)
+            .and_then(|n| n.checked_mul(unit))
+            .ok_or(format!("expected a size like `512M` or `2G`, got `{s}`."))
+    }
+
+    fn describe(self, abort: Abort) -> String {
+        match (abort, self.timeout, self.memory) {
+            (Abort::Timeout, Some(timeout), _) => {
+                format!("killed after {}", format_nanos(timeout.as_nanos() as f64))
+            }
+            (Abort::OutOfMemory, _, Some(bytes)) => {
+                format!("exceeded the memory limit of {}MiB", bytes >> 20)
+            }
+            _ => "killed".into(),
+        }
+    }
+}
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Runs the solutions of `year` for `days_to_run` in day order, with `options` passed on to every solution.
+///
+/// With more than one job, all solution binaries are built up front and days are executed concurrently.
+/// Their output is buffered and printed grouped by day, still in day order.
+///
+/// With `is_memory`, every day is run once more as a build with the `dhat-heap` feature, and the
+/// heap usage of every part is added to its timing.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_memory: bool,
+    options: &RunOptions,
+    executor: Executor,
+) -> Option<Timings> {
+    let (is_timed, format) = (options.time, options.format);
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
+    let revision = git_revision();
+    let profile = if is_release { "release" } else { "dev" };
+
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
+    let mut print_header = |day: Day| {
+        if format == OutputFormat::Human {
             if need_space {
                 println!();
             }
@@ -23,119 +122,584 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
+        }
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let mut push_timing = |val: Timing| {
+        timings.push(Timing {
+            timestamp: unix_timestamp(),
+            git_revision: revision.clone(),
+            profile: Some(profile.into()),
+            ..val
+        });
+    };
+
+    let print_not_solved = || {
+        if format == OutputFormat::Human {
+            println!("Not solved.");
+        }
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
+    let mut aborted: Vec<(Day, Abort)> = vec![];
+
+    let mut collect = |day: Day, output: Result<DayOutput, Error>, limits: Limits| {
+        // e.g. the solution could not be spawned. Skip the day and carry on with the rest.
+        let (output, memory) = match output {
+            Ok(output) => output,
+            Err(e) => {
+                match format {
+                    OutputFormat::Human => println!("{ANSI_RED}Failed to run:{ANSI_RESET} {e}"),
+                    OutputFormat::Json => eprintln!("Day {day}: failed to run: {e}"),
+                }
+                return;
+            }
+        };
+
+        if let Some(abort) = output.aborted {
+            let reason = limits.describe(abort);
+            // keep stdout parseable when emitting JSON records.
+            match format {
+                OutputFormat::Human => println!("{ANSI_RED}{abort}{ANSI_RESET} ({reason})"),
+                OutputFormat::Json => eprintln!("Day {day}: {abort} ({reason})"),
+            }
+            aborted.push((day, abort));
+        } else if output.stdout.is_empty() {
+            print_not_solved();
+            return;
+        }
+
+        let mut timing = match format {
+            OutputFormat::Human => child_commands::parse_exec_time(&output.stdout, day),
+            OutputFormat::Json => child_commands::parse_reports(&output.stdout, day),
+        };
+        timing.aborted = output.aborted;
+
+        if let Some(memory) = memory {
+            let lines = match memory.aborted {
+                Some(abort) => vec![format!("Heap profiling: {abort}")],
+                None => {
+                    let reports: Vec<PartReport> = memory
+                        .stdout
+                        .iter()
+                        .filter_map(|l| l.parse().ok())
+                        .collect();
+                    attach_memory(&mut timing, &reports)
+                }
+            };
+
+            for line in lines {
+                match format {
+                    OutputFormat::Human => println!("{line}"),
+                    OutputFormat::Json => eprintln!("Day {day}: {line}"),
+                }
+            }
+        }
+
+        push_timing(timing);
+    };
+
+    // NOTE: use non-duplicate, sorted day values.
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    match executor {
+        Executor::InProcess => {
+            if is_memory {
+                eprintln!("Warning: --memory is ignored when running solutions in-process.");
+            }
+
+            days.iter().for_each(|day| {
+                print_header(*day);
+                match run_in_process(year, *day, options) {
+                    Some(reports) => push_timing(timing_from_reports(*day, &reports)),
+                    None => print_not_solved(),
+                }
+            });
+        }
+        Executor::Process { jobs, limits } => {
+            let profile = Profile::from_release(is_release);
+            let profiles = if is_memory {
+                vec![profile, Profile::Dhat]
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                vec![profile]
+            };
+
+            // solution bins are invoked directly, so that limits apply to them and not to cargo.
+            for profile in profiles {
+                if let Err(e) = child_commands::build_solutions(profile) {
+                    eprintln!("Failed to build solutions: {e:?}");
+                    return None;
+                }
             }
-        });
+
+            // heap profiling is slow, so the profiled build is run once and not benched.
+            let memory_options = RunOptions {
+                time: false,
+                format: OutputFormat::Json,
+                ..options.clone()
+            };
+
+            let run = |day: Day, forward: bool| -> Result<DayOutput, Error> {
+                let scope = Scope::resolve(year, day);
+                let output =
+                    child_commands::run_solution(scope, day, profile, options, limits, forward)?;
+
+                let memory = if is_memory && !output.stdout.is_empty() {
+                    Some(child_commands::run_solution(
+                        scope,
+                        day,
+                        Profile::Dhat,
+                        &memory_options,
+                        limits,
+                        false,
+                    )?)
+                } else {
+                    None
+                };
+
+                Ok((output, memory))
+            };
+
+            if jobs > 1 {
+                run_parallel(
+                    &days,
+                    jobs,
+                    |day| run(day, false),
+                    |day, output| {
+                        print_header(day);
+
+                        if let Ok((output, _)) = &output {
+                            output.stdout.iter().for_each(|line| println!("{line}"));
+                            output.stderr.iter().for_each(|line| eprintln!("{line}"));
+                        }
+
+                        collect(day, output, limits);
+                    },
+                );
+            } else {
+                days.iter().for_each(|day| {
+                    print_header(*day);
+                    collect(*day, run(*day, true), limits);
+                });
+            }
+        }
+    }
+
+    if !aborted.is_empty() {
+        let days: Vec<String> = aborted
+            .iter()
+            .map(|(day, abort)| format!("day {day} ({abort})"))
+            .collect();
+        let summary = format!("{ANSI_BOLD}Aborted:{ANSI_RESET} {}", days.join(", "));
+
+        match format {
+            OutputFormat::Human => println!("\n{summary}"),
+            OutputFormat::Json => eprintln!("{summary}"),
+        }
+    }
 
     if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
+        if format == OutputFormat::Human {
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
         Some(timings)
     } else {
         None
     }
 }
 
+/// Output of the run of a day, and of its heap profiling run if memory is measured.
+type DayOutput = (SolutionOutput, Option<SolutionOutput>);
+
+/// Adds the heap usage in `reports` to the parts of `timing` that have a timing.
+/// Returns a line that describes the heap usage of every part.
+fn attach_memory(timing: &mut Timing, reports: &[PartReport]) -> Vec<String> {
+    let mut lines = vec![];
+
+    for report in reports.iter().filter(|report| report.day == timing.day) {
+        let (label, part) = match report.part {
+            PARSE => ("Parse", timing.parse.as_mut()),
+            1 => ("Part 1", timing.part_1.as_mut()),
+            2 => ("Part 2", timing.part_2.as_mut()),
+            _ => continue,
+        };
+
+        let (Some(part), Some(memory)) = (part, report.memory) else {
+            continue;
+        };
+
+        lines.push(format!(
+            "{label} heap: {} at peak, {} total in {} allocations",
+            format_bytes(memory.peak_bytes),
+            format_bytes(memory.total_bytes),
+            memory.total_blocks
+        ));
+        part.memory = Some(memory);
+    }
+
+    lines
+}
+
+/// Runs the registered solution for `day` of `year` in the current process.
+/// Returns `None` if the day has no solution, its input is missing or the solution panicked.
+pub fn run_in_process(year: Year, day: Day, options: &RunOptions) -> Option<Vec<PartReport>> {
+    let solution = registry::find(year, day)?;
+
+    let Ok(input) = fs::read_to_string(solution.scope.input_path(day)) else {
+        eprintln!("Could not read input for day {day}, skipping.");
+        return None;
+    };
+
+    // the panic message is printed by the default hook, skip the day and carry on with the rest.
+    panic::catch_unwind(|| (solution.run)(&input, options)).ok()
+}
+
+/// Collects the timings of all answered parts in `reports`.
+pub fn timing_from_reports<'a>(
+    day: Day,
+    reports: impl IntoIterator<Item = &'a PartReport>,
+) -> Timing {
+    let mut timings = Timing::new(day);
+
+    reports
+        .into_iter()
+        .filter(|report| report.day == day)
+        .for_each(|report| {
+            if report.error.is_some() {
+                timings.set_failed(report.part);
+            }
+
+            // the parse stage has no answer of its own, only whether it succeeded.
+            if report.answer.is_none() && !(report.is_parse() && report.error.is_none()) {
+                return;
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            let timing = PartTiming {
+                nanos: report.stats.mean.as_nanos() as f64,
+                samples: Some(report.stats.samples),
+                memory: report.memory,
+            };
+
+            timings.set_part(report.part, timing);
+        });
+
+    timings
+}
+
+/// Runs `run` for every day on up to `jobs` worker threads.
+/// `on_done` is called on the current thread in the order of `days`, as soon as all preceding days are done.
+fn run_parallel<T: Send>(
+    days: &[Day],
+    jobs: usize,
+    run: impl Fn(Day) -> T + Sync,
+    mut on_done: impl FnMut(Day, T),
+) {
+    let queue = Mutex::new(days.iter().copied());
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.min(days.len()) {
+            let (queue, run, tx) = (&queue, &run, tx.clone());
+            s.spawn(move || loop {
+                let Some(day) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                tx.send((day, run(day))).unwrap();
+            });
+        }
+
+        // drop the original sender so that `rx` closes once all workers are done.
+        drop(tx);
+
+        let mut finished: BTreeMap<Day, T> = BTreeMap::new();
+        let mut next = days.iter().copied().peekable();
+
+        for (day, result) in rx {
+            finished.insert(day, result);
+
+            while let Some(result) = next.peek().and_then(|day| finished.remove(day)) {
+                on_done(next.next().unwrap(), result);
+            }
+        }
+    });
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    BuildFailed,
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read the output of the solution."),
+            Error::BuildFailed => write!(f, "the solution could not be built."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{Error, Limits};
+    use crate::template::{
+        report::{PartReport, PARSE},
+        runner::{RunOptions, FAILED},
+        timings::{parse_duration, Abort, PartTiming, Timing},
+        Day, Scope, ANSI_RED, ANSI_RESET,
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        env,
+        env::consts::EXE_SUFFIX,
+        io::{self, BufRead, BufReader},
+        path::{Path, PathBuf},
+        process::{Child, Command, ExitStatus, Stdio},
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// Printed by the standard library when an allocation fails, e.g. because of a memory limit.
+    const ALLOCATION_FAILED: &str = "memory allocation of ";
+
+    /// How often a solution with a timeout is checked for having exited.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Output of a solution bin.
+    pub struct SolutionOutput {
+        pub stdout: Vec<String>,
+        pub stderr: Vec<String>,
+        /// Set if the solution was killed before it finished.
+        pub aborted: Option<Abort>,
+    }
+
+    /// Cargo profile that solution bins are built with.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Profile {
+        Dev,
+        Release,
+        /// The `dhat` profile with the `dhat-heap` feature, which measures heap usage.
+        Dhat,
+        /// The `dhat` profile without the allocator, an optimized build with debug info for profilers.
+        Profiling,
+    }
+
+    impl Profile {
+        pub fn from_release(is_release: bool) -> Self {
+            if is_release {
+                Profile::Release
+            } else {
+                Profile::Dev
+            }
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        fn build_args(self) -> &'static [&'static str] {
+            match self {
+                Profile::Dev => &[],
+                Profile::Release => &["--release"],
+                Profile::Dhat => &["--profile", "dhat", "--features", "dhat-heap"],
+                Profile::Profiling => &["--profile", "dhat"],
+            }
+        }
 
-        if is_release {
-            args.push("--release");
+        fn target_dir(self) -> &'static str {
+            match self {
+                Profile::Dev => "debug",
+                Profile::Release => "release",
+                Profile::Dhat | Profile::Profiling => "dhat",
+            }
         }
+    }
+
+    /// Build all solution bins so that they can be invoked without going through `cargo run`.
+    pub fn build_solutions(profile: Profile) -> Result<(), Error> {
+        build(&["--bins"], profile)
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+    /// Build the solution bin of a single day.
+    pub fn build_solution(scope: Scope, day: Day, profile: Profile) -> Result<(), Error> {
+        build(&["--bin", &scope.bin_name(day)], profile)
+    }
+
+    fn build(targets: &[&str], profile: Profile) -> Result<(), Error> {
+        let status = Command::new("cargo")
+            .args(["build", "--quiet"])
+            .args(targets)
+            .args(profile.build_args())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::BuildFailed)
         }
+    }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+    /// The cargo target directory, `target` unless overridden via `CARGO_TARGET_DIR`.
+    pub fn target_dir() -> PathBuf {
+        env::var_os("CARGO_TARGET_DIR").map_or_else(|| "target".into(), PathBuf::from)
+    }
+
+    /// Path of a solution bin built by [`build_solutions`].
+    pub fn get_path_for_executable(scope: Scope, day: Day, profile: Profile) -> PathBuf {
+        target_dir()
+            .join(profile.target_dir())
+            .join(format!("{}{EXE_SUFFIX}", scope.bin_name(day)))
+    }
+
+    /// Run the pre-built solution bin for a given day within `limits`, capturing its output.
+    /// With `forward`, output is also printed while the solution runs.
+    pub fn run_solution(
+        scope: Scope,
+        day: Day,
+        profile: Profile,
+        options: &RunOptions,
+        limits: Limits,
+        forward: bool,
+    ) -> Result<SolutionOutput, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&scope.bin_path(day)).exists() {
+            return Ok(SolutionOutput {
+                stdout: vec![],
+                stderr: vec![],
+                aborted: None,
+            });
+        }
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
+        let mut cmd = Command::new(get_path_for_executable(scope, day, profile));
+        cmd.args(options.to_args())
+            .stdin(Stdio::null())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+            .stderr(Stdio::piped());
+
+        if let Some(bytes) = limits.memory {
+            limit_memory(&mut cmd, bytes);
+        }
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let mut child = cmd.spawn()?;
 
-        let mut output = vec![];
+        let stdout = BufReader::new(child.stdout.take().ok_or(Error::BrokenPipe)?);
+        let stderr = BufReader::new(child.stderr.take().ok_or(Error::BrokenPipe)?);
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        // read both pipes on separate threads, so that the timeout can be checked meanwhile.
+        let stdout = thread::spawn(move || read_lines(stdout, |line| println!("{line}"), forward));
+        let stderr = thread::spawn(move || read_lines(stderr, |line| eprintln!("{line}"), forward));
+
+        let status = wait(&mut child, limits.timeout)?;
+
+        let stdout = stdout.join().unwrap();
+        let stderr = stderr.join().unwrap();
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        Ok(SolutionOutput {
+            aborted: classify_abort(status, &stderr),
+            stdout,
+            stderr,
+        })
+    }
+
+    /// Why a solution was aborted, given its exit status as returned by [`wait`] and its stderr.
+    fn classify_abort(status: Option<ExitStatus>, stderr: &[String]) -> Option<Abort> {
+        match status {
+            None => Some(Abort::Timeout),
+            Some(status)
+                if !status.success() && stderr.iter().any(|l| l.starts_with(ALLOCATION_FAILED)) =>
+            {
+                Some(Abort::OutOfMemory)
+            }
+            Some(_) => None,
         }
+    }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    fn read_lines(reader: impl BufRead, print: impl Fn(&str), forward: bool) -> Vec<String> {
+        reader
+            .lines()
+            .map_while(Result::ok)
+            .inspect(|line| {
+                if forward {
+                    print(line);
+                }
+            })
+            .collect()
+    }
+
+    /// Waits for `child` to exit. Kills it once it runs longer than `timeout`, returning `None`.
+    fn wait(child: &mut Child, timeout: Option<Duration>) -> io::Result<Option<ExitStatus>> {
+        let Some(timeout) = timeout else {
+            return child.wait().map(Some);
+        };
+
+        let start = Instant::now();
+
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(Some(status));
+            }
 
-        Ok(output)
+            if start.elapsed() >= timeout {
+                child.kill()?;
+                child.wait()?;
+                return Ok(None);
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    /// Limits the address space of the process spawned by `cmd` to `bytes`.
+    #[cfg(unix)]
+    fn limit_memory(cmd: &mut Command, bytes: u64) {
+        use std::os::unix::process::CommandExt;
+
+        let limit = libc::rlimit {
+            rlim_cur: bytes,
+            rlim_max: bytes,
         };
 
+        // SAFETY: `setrlimit` is async-signal-safe and does not allocate, so it may be called
+        // between fork and exec.
+        unsafe {
+            cmd.pre_exec(move || {
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                    Ok(())
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            });
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn limit_memory(_cmd: &mut Command, _bytes: u64) {}
+
+    pub fn parse_exec_time(output: &[String], day: Day) -> Timing {
+        let mut timings = Timing::new(day);
+
+        let failed = format!(": {ANSI_RED}{FAILED}{ANSI_RESET}");
+        output.iter().filter(|l| l.contains(&failed)).for_each(|l| {
+            if l.starts_with("Parse") {
+                timings.set_failed(PARSE);
+            } else if l.contains("Part 1") {
+                timings.set_failed(1);
+            } else if l.contains("Part 2") {
+                timings.set_failed(2);
+            }
+        });
+
         output
             .iter()
             .filter_map(|l| {
@@ -143,50 +707,52 @@ pub mod child_commands {
                     return None;
                 }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
+                let Some((nanos, samples)) = parse_time(l) else {
                     eprintln!("Could not parse timings from line: {l}");
                     return None;
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                Some((part, nanos, samples))
             })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
+            .for_each(|(part, nanos, samples)| {
+                let timing = PartTiming {
+                    nanos,
+                    samples,
+                    memory: None,
+                };
+
+                if part.starts_with("Parse") {
+                    timings.set_part(PARSE, timing);
+                } else if part.contains("Part 1") {
+                    timings.set_part(1, timing);
                 } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                    timings.set_part(2, timing);
                 }
-
-                timings.total_nanos += nanos;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Collect the JSON records printed by a child invoked with `--format json`.
+    pub fn parse_reports(output: &[String], day: Day) -> Timing {
+        let reports: Vec<PartReport> = output.iter().filter_map(|l| l.parse().ok()).collect();
+        super::timing_from_reports(day, &reports)
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
+    /// Parses the mean duration in nanoseconds and the sample count from a line like `Part 1: 42 (1.2ms @ 10 samples)`.
+    fn parse_time(line: &str) -> Option<(f64, Option<u128>)> {
+        let (str_timing, str_samples) = line
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+            .next_back()?
+            .split_once('@')?;
 
-        Some((str_timing, parsed_timing))
+        Some((
+            parse_duration(str_timing.trim())?,
+            str_samples.trim().parse().ok(),
+        ))
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,9 +771,59 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::process::Command;
+        use std::time::{Duration, Instant};
+
+        use super::{classify_abort, parse_exec_time, parse_reports, wait};
+
+        use crate::{day, template::timings::Abort};
+
+        #[cfg(unix)]
+        #[test]
+        fn kills_solutions_after_timeout() {
+            let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+            let start = Instant::now();
+
+            let status = wait(&mut child, Some(Duration::from_millis(50))).unwrap();
+
+            assert_eq!(status, None);
+            assert!(start.elapsed() < Duration::from_secs(5));
+            // the child was waited for after being killed, so it is not left as a zombie.
+            assert!(child.try_wait().unwrap().is_some());
+        }
 
-        use crate::day;
+        #[cfg(unix)]
+        #[test]
+        fn waits_for_solutions_within_timeout() {
+            let mut child = Command::new("true").spawn().unwrap();
+            let status = wait(&mut child, Some(Duration::from_secs(10))).unwrap();
+            assert!(status.unwrap().success());
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn classifies_aborts() {
+            use std::os::unix::process::ExitStatusExt;
+            use std::process::ExitStatus;
+
+            // wait statuses: exit code 0, exit code 1 and killed by SIGABRT.
+            let (success, failure, abort) = (
+                ExitStatus::from_raw(0),
+                ExitStatus::from_raw(1 << 8),
+                ExitStatus::from_raw(6),
+            );
+            let oom = vec!["memory allocation of 1073741824 bytes failed".to_string()];
+            let panic = vec!["thread 'main' panicked at src/bin/01.rs:5:9".to_string()];
+
+            assert_eq!(classify_abort(None, &[]), Some(Abort::Timeout));
+            assert_eq!(classify_abort(Some(abort), &oom), Some(Abort::OutOfMemory));
+            assert_eq!(
+                classify_abort(Some(failure), &oom),
+                Some(Abort::OutOfMemory)
+            );
+            assert_eq!(classify_abort(Some(failure), &panic), None);
+            assert_eq!(classify_abort(Some(success), &oom), None);
+        }
 
         #[test]
         fn parses_execution_times() {
@@ -220,8 +836,12 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            let part_1 = res.part_1.unwrap();
+            assert_approx_eq!(part_1.nanos, 74.13_f64);
+            assert_eq!(part_1.samples, Some(100000));
+            let part_2 = res.part_2.unwrap();
+            assert_approx_eq!(part_2.nanos, 74130000_f64);
+            assert_eq!(part_2.samples, Some(99999));
         }
 
         #[test]
@@ -235,8 +855,8 @@ pub mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.part_1.unwrap().nanos, 2000000000_f64);
+            assert_approx_eq!(res.part_2.unwrap().nanos, 100000000_f64);
         }
 
         #[test]
@@ -252,6 +872,181 @@ pub mod child_commands {
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.failed, vec![]);
+        }
+
+        #[test]
+        fn parses_failed_parts() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: \x1b[31mfailed\x1b[0m (1.2µs)".into(),
+                    "Part 2: ✖        ".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_1.is_none(), true);
+            assert_eq!(res.failed, vec![1]);
+
+            let res = parse_reports(
+                &[
+                    r#"{"day":1,"part":2,"answer":null,"error":"bad input","samples":1,"outliers":0,"duration_nanos":5,"stddev_nanos":0,"min_nanos":5,"max_nanos":5,"median_nanos":5,"p95_nanos":5}"#.into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.failed, vec![2]);
+        }
+
+        #[test]
+        fn parses_parse_stage() {
+            let res = parse_exec_time(
+                &[
+                    "Parse: (2.0ms @ 50 samples)".into(),
+                    "Part 1: 42 (74.13ns @ 100000 samples)".into(),
+                ],
+                day!(1),
+            );
+            let parse = res.parse.unwrap();
+            assert_approx_eq!(parse.nanos, 2_000_000_f64);
+            assert_eq!(parse.samples, Some(50));
+            assert_approx_eq!(res.total_nanos, 2_000_074.13_f64);
+
+            let res = parse_reports(
+                &[
+                    r#"{"day":1,"part":0,"answer":null,"error":null,"samples":10,"outliers":0,"duration_nanos":20,"stddev_nanos":0,"min_nanos":20,"max_nanos":20,"median_nanos":20,"p95_nanos":20}"#.into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.parse.unwrap().nanos, 20_f64);
+
+            let res = parse_exec_time(&["Parse: \x1b[31mfailed\x1b[0m (1.2µs)".into()], day!(1));
+            assert_eq!(res.parse.is_none(), true);
+            assert_eq!(res.failed, vec![0]);
+        }
+
+        #[test]
+        fn parses_json_reports() {
+            let res = parse_reports(
+                &[
+                    r#"{"day":1,"part":1,"answer":"0","samples":100,"outliers":0,"duration_nanos":74,"stddev_nanos":2,"min_nanos":70,"max_nanos":80,"median_nanos":74,"p95_nanos":78}"#.into(),
+                    r#"{"day":1,"part":2,"answer":null,"samples":1,"outliers":0,"duration_nanos":5,"stddev_nanos":0,"min_nanos":5,"max_nanos":5,"median_nanos":5,"p95_nanos":5}"#.into(),
+                    "Submitting result via aoc-cli...".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 74_f64);
+            let part_1 = res.part_1.unwrap();
+            assert_approx_eq!(part_1.nanos, 74_f64);
+            assert_eq!(part_1.samples, Some(100));
+            assert_eq!(res.part_2.is_none(), true);
         }
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::{attach_memory, run_parallel, Executor, Limits};
+    use crate::{
+        day,
+        template::{
+            report::{PartReport, PARSE},
+            stats::{MemoryStats, Stats},
+            timings::{PartTiming, Timing},
+        },
+    };
+
+    #[test]
+    fn parses_timeouts() {
+        assert_eq!(Limits::parse_timeout("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(Limits::parse_timeout("30"), Ok(Duration::from_secs(30)));
+        assert_eq!(
+            Limits::parse_timeout("500ms"),
+            Ok(Duration::from_millis(500))
+        );
+        assert!(Limits::parse_timeout("0s").is_err());
+        assert!(Limits::parse_timeout("soon").is_err());
+    }
+
+    #[test]
+    fn parses_jobs() {
+        assert_eq!(Executor::parse_jobs("4"), Ok(4));
+        assert!(Executor::parse_jobs("0").is_err());
+        assert!(Executor::parse_jobs("all").is_err());
+    }
+
+    #[test]
+    fn runs_in_parallel_in_day_order() {
+        let days = [day!(1), day!(2), day!(3), day!(4), day!(5)];
+        let mut done = vec![];
+
+        // later days finish first.
+        run_parallel(
+            &days,
+            days.len(),
+            |day| {
+                thread::sleep(Duration::from_millis(
+                    10 * (6 - u64::from(day.into_inner())),
+                ));
+                day.into_inner()
+            },
+            |day, result| done.push((day, result)),
+        );
+
+        assert_eq!(
+            done,
+            days.iter()
+                .map(|day| (*day, day.into_inner()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parses_memory_sizes() {
+        assert_eq!(Limits::parse_memory("1048576"), Ok(1 << 20));
+        assert_eq!(Limits::parse_memory("512K"), Ok(512 << 10));
+        assert_eq!(Limits::parse_memory("512M"), Ok(512 << 20));
+        assert_eq!(Limits::parse_memory("2g"), Ok(2 << 30));
+        assert!(Limits::parse_memory("0M").is_err());
+        assert!(Limits::parse_memory("lots").is_err());
+        assert!(Limits::parse_memory("M").is_err());
+    }
+
+    #[test]
+    fn attaches_memory_to_timed_parts() {
+        let memory = MemoryStats {
+            total_bytes: 2048,
+            total_blocks: 3,
+            peak_bytes: 1024,
+            peak_blocks: 1,
+        };
+        let report = |part| PartReport {
+            day: day!(1),
+            part,
+            answer: None,
+            error: None,
+            stats: Stats::single(Duration::ZERO),
+            memory: Some(memory),
+        };
+        let part = Some(PartTiming {
+            nanos: 1000.0,
+            samples: Some(1),
+            memory: None,
+        });
+
+        let mut timing = Timing {
+            part_1: part.clone(),
+            ..Timing::new(day!(1))
+        };
+        let lines = attach_memory(&mut timing, &[report(PARSE), report(1), report(2)]);
+
+        assert_eq!(
+            lines,
+            vec!["Part 1 heap: 1.0KiB at peak, 2.0KiB total in 3 allocations"]
+        );
+        assert_eq!(timing.part_1.unwrap().memory, Some(memory));
+        assert!(timing.parse.is_none());
+        assert!(timing.part_2.is_none());
+    }
+}
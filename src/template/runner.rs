@@ -1,67 +1,593 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, Read, Write};
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::aoc_cli::SubmissionOutcome;
+use crate::template::config::{Config, SubmitPolicy};
+use crate::template::report::{OutputFormat, PartReport, PARSE};
+use crate::template::stats::{MemoryStats, Stats};
+use crate::template::submissions::{Submission, Submissions};
+use crate::template::timings::parse_duration;
+use crate::template::{answers, Day, Scope, ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Printed instead of the answer of a part that returned an error.
+pub const FAILED: &str = "failed";
+
+const MAX_WARMUP_ITERATIONS: u128 = 1000;
+
+/// Approximate time a part is run for when it is profiled.
+const PROFILE_DURATION: Duration = Duration::from_secs(5);
+
+/// Options that control how solution parts are run and reported.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Bench every part instead of running it once.
+    pub time: bool,
+    pub format: OutputFormat,
+    /// Submit the answer of this part via aoc-cli.
+    pub submit: Option<u8>,
+    pub input: InputSource,
+    /// Run only this part, repeatedly, so that it can be recorded by a profiler.
+    pub profile_part: Option<u8>,
+    pub bench: BenchConfig,
+    /// Only collect the reports of parts, without printing them, e.g. for `cargo verify`. Errors
+    /// are still printed to stderr. Not passed on to solution binaries.
+    pub quiet: bool,
+}
+
+/// How long and how often parts are run when they are benched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Approximate time spent collecting benchmark samples.
+    pub budget: Duration,
+    /// Approximate time spent warming up caches and branch predictors before sampling.
+    pub warmup: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_secs(1),
+            warmup: Duration::from_millis(100),
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Parses a budget or warm-up like `2s`, `100ms` or `0`.
+    pub fn parse_duration(s: &str) -> Result<Duration, String> {
+        parse_duration(s)
+            .filter(|nanos| *nanos >= 0.0)
+            .map(|nanos| Duration::from_secs_f64(nanos / 1_000_000_000_f64))
+            .ok_or(format!("expected a duration like `2s`, got `{s}`."))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_samples == 0 {
+            return Err("the minimum number of samples must be at least 1.".into());
+        }
+
+        if self.min_samples > self.max_samples {
+            return Err(format!(
+                "the minimum number of samples ({}) exceeds the maximum ({}).",
+                self.min_samples, self.max_samples
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Arguments that make a solution binary use this config, see [`RunOptions::from_args`].
+    fn to_args(self) -> Vec<String> {
+        vec![
+            "--bench-budget".into(),
+            format!("{}ns", self.budget.as_nanos()),
+            "--bench-warmup".into(),
+            format!("{}ns", self.warmup.as_nanos()),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+        ]
+    }
+}
+
+/// Where a solution binary reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/inputs`.
+    #[default]
+    Puzzle,
+    /// A file passed via `--input <path>`.
+    File(PathBuf),
+    /// Standard input, selected via `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    fn read(&self, scope: Scope, day: Day) -> Result<String, String> {
+        match self {
+            InputSource::Puzzle => {
+                let path = scope.input_path(day);
+                fs::read_to_string(&path)
+                    .map_err(|e| format!("could not open input file \"{path}\": {e}"))
+            }
+            InputSource::File(path) => fs::read_to_string(path)
+                .map_err(|e| format!("could not open input file \"{}\": {e}", path.display())),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("could not read input from stdin: {e}"))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl RunOptions {
+    /// Reads the options from the arguments passed to a solution binary,
+    /// e.g. `--time`, `--format json`, `--submit 1`, `--input <path>` or `--profile-part 2`.
+    /// Benchmark settings default to the ones in `aoc.toml`.
+    pub fn from_env() -> Self {
+        let args: Vec<String> = env::args().collect();
+        Self::from_args(&args, Config::get().bench)
+    }
+
+    /// Parses the arguments of a solution binary. Benchmark flags override `bench`.
+    fn from_args(args: &[String], bench: BenchConfig) -> Self {
+        let value_of = |flag: &str| args.iter().position(|x| x == flag).map(|i| args.get(i + 1));
+
+        let bench = apply_bench_args(bench, value_of).unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input: {e}");
+            process::exit(1);
+        });
+
+        let submit = value_of("--submit").map(|value| {
+            let Some(Ok(part)) = value.map(|x| x.parse::<u8>()) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                process::exit(1);
+            };
+            part
+        });
+
+        let input = match value_of("--input") {
+            None => InputSource::Puzzle,
+            Some(Some(path)) if path == "-" => InputSource::Stdin,
+            Some(Some(path)) => InputSource::File(path.into()),
+            Some(None) => {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --input <path|->");
+                process::exit(1);
+            }
+        };
+
+        let profile_part = value_of("--profile-part").map(|value| {
+            let Some(Ok(part @ 1..=2)) = value.map(|x| x.parse::<u8>()) else {
+                eprintln!("Unexpected command-line input. Format: --profile-part <1|2>");
+                process::exit(1);
+            };
+            part
+        });
+
+        let format = value_of("--format").map_or(Ok(OutputFormat::default()), |value| {
+            value.map_or(Err("Format: --format <human|json>".into()), |x| x.parse())
+        });
+        let format = format.unwrap_or_else(|e: String| {
+            eprintln!("Unexpected command-line input: {e}");
+            process::exit(1);
+        });
+
+        // answers for other inputs than the puzzle input would be wrong.
+        if submit.is_some() && input != InputSource::Puzzle {
+            eprintln!("--submit can not be combined with --input.");
+            process::exit(1);
+        }
+
+        Self {
+            time: args.iter().any(|x| x == "--time"),
+            format,
+            submit,
+            input,
+            profile_part,
+            bench,
+            quiet: false,
+        }
+    }
+
+    /// Arguments that make a solution binary run with these options, the inverse of `from_env`.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if self.time {
+            args.push("--time".to_string());
+            args.extend(self.bench.to_args());
+        }
+
+        if self.format != OutputFormat::Human {
+            args.extend(["--format".to_string(), self.format.to_string()]);
+        }
+
+        if let Some(part) = self.submit {
+            args.extend(["--submit".to_string(), part.to_string()]);
+        }
+
+        match &self.input {
+            InputSource::Puzzle => {}
+            InputSource::File(path) => {
+                args.extend(["--input".to_string(), path.display().to_string()]);
+            }
+            InputSource::Stdin => args.extend(["--input".to_string(), "-".to_string()]),
+        }
+
+        if let Some(part) = self.profile_part {
+            args.extend(["--profile-part".to_string(), part.to_string()]);
+        }
+
+        args
+    }
+
+    /// The format parts are printed in, or `None` if they are not printed.
+    fn output(&self) -> Option<OutputFormat> {
+        (!self.quiet).then_some(self.format)
+    }
+
+    /// Reads the input selected via `--input`, exiting if it can not be read.
+    pub fn read_input(&self, scope: Scope, day: Day) -> String {
+        self.input.read(scope, day).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        })
+    }
+}
+
+/// Applies `--bench-budget`, `--bench-warmup`, `--min-samples` and `--max-samples` to `bench`.
+fn apply_bench_args<'a>(
+    mut bench: BenchConfig,
+    value_of: impl Fn(&str) -> Option<Option<&'a String>>,
+) -> Result<BenchConfig, String> {
+    let value =
+        |flag: &str| value_of(flag).map(|value| value.ok_or(format!("{flag} requires a value.")));
+
+    if let Some(budget) = value("--bench-budget") {
+        bench.budget = BenchConfig::parse_duration(budget?)?;
+    }
+    if let Some(warmup) = value("--bench-warmup") {
+        bench.warmup = BenchConfig::parse_duration(warmup?)?;
+    }
+    if let Some(min) = value("--min-samples") {
+        bench.min_samples = parse_samples(min?)?;
+    }
+    if let Some(max) = value("--max-samples") {
+        bench.max_samples = parse_samples(max?)?;
+    }
+
+    bench.validate()?;
+    Ok(bench)
+}
+
+/// Parses a number of benchmark samples.
+pub fn parse_samples(s: &str) -> Result<u128, String> {
+    s.parse()
+        .map_err(|_| format!("expected a number of samples, got `{s}`."))
+}
+
+/// Return types a solution part may have.
+///
+/// Parts return `Option<T>`, with `None` meaning the part is not implemented (yet),
+/// or `anyhow::Result<T>` if they can fail, e.g. on a malformed input.
+pub trait PartResult {
+    /// The answer of the part, or the error chain if the part failed.
+    fn answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display> PartResult for anyhow::Result<T> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(result) => Ok(Some(result.to_string())),
+            Err(e) => Err(format_error_chain(e)),
+        }
+    }
+}
+
+/// Formats an error with its causes like anyhow's `Debug` output, but without a backtrace.
+fn format_error_chain(error: &anyhow::Error) -> String {
+    let mut causes = error.chain().skip(1).peekable();
+
+    if causes.peek().is_none() {
+        return error.to_string();
+    }
+
+    let causes: Vec<String> = causes
+        .enumerate()
+        .map(|(i, cause)| format!("    {i}: {cause}"))
+        .collect();
+
+    format!("{error}\n\nCaused by:\n{}", causes.join("\n"))
+}
+
+/// Return types the parse stage of a solution may have, see [`PartResult`].
+pub trait ParseResult {
+    type Output;
+
+    fn succeeded(&self) -> bool;
+
+    /// The parsed input, or the error chain if parsing failed.
+    fn into_parsed(self) -> Result<Self::Output, String>;
+}
+
+impl<T> ParseResult for Option<T> {
+    type Output = T;
+
+    fn succeeded(&self) -> bool {
+        self.is_some()
+    }
+
+    fn into_parsed(self) -> Result<T, String> {
+        self.ok_or_else(|| "parse returned no value".into())
+    }
+}
+
+impl<T> ParseResult for anyhow::Result<T> {
+    type Output = T;
+
+    fn succeeded(&self) -> bool {
+        self.is_ok()
+    }
+
+    fn into_parsed(self) -> Result<T, String> {
+        self.map_err(|e| format_error_chain(&e))
+    }
+}
+
+/// Runs the parse stage of a solution, whose output is shared by both parts.
+/// Returns the parsed input, or `None` if parsing failed, and the report of the stage.
+pub fn run_parse<'a, R: ParseResult>(
+    func: impl Fn(&'a str) -> R,
+    input: &'a str,
+    day: Day,
+    options: &RunOptions,
+) -> (Option<R::Output>, PartReport) {
+    let format = options.output();
+
+    // when profiling a part, its parsed input is only needed once.
+    let options = &RunOptions {
+        profile_part: None,
+        ..options.clone()
+    };
+
+    let (result, stats, memory) = run_timed(func, input, options, |result| {
+        if format == Some(OutputFormat::Human) {
+            print!("Parse: ");
+            let _ = stdout().flush();
+        }
+        result.succeeded()
+    });
+
+    let result = result.into_parsed();
+
+    let report = PartReport {
+        day,
+        part: PARSE,
+        answer: None,
+        error: result.as_ref().err().cloned(),
+        stats,
+        memory,
+    };
+
+    match format {
+        None => {}
+        Some(OutputFormat::Human) => {
+            let duration_str = format_duration(&report.stats.mean, report.stats.samples);
+            print!("\r");
+            if report.error.is_some() {
+                println!("Parse: {ANSI_RED}{FAILED}{ANSI_RESET}{duration_str}");
+            } else {
+                println!("Parse:{duration_str}");
+            }
+        }
+        Some(OutputFormat::Json) => {
+            println!("{}", report.to_json_line());
+        }
+    }
+
+    if let Some(error) = &report.error {
+        eprintln!("Day {day}, parsing failed: {error}");
+    }
+
+    (result.ok(), report)
+}
+
+/// Reports `part` as failed without running it, because its input could not be parsed.
+pub fn fail_part(day: Day, part: u8, options: &RunOptions) -> PartReport {
+    let result = Err("the input could not be parsed".to_string());
+
+    let report = PartReport {
+        day,
+        part,
+        answer: None,
+        error: result.clone().err(),
+        stats: Stats::single(Duration::ZERO),
+        memory: None,
+    };
+
+    match options.output() {
+        None => {}
+        Some(OutputFormat::Human) => {
+            print_result(&result, &format!("Part {part}"), " (skipped)");
+        }
+        Some(OutputFormat::Json) => println!("{}", report.to_json_line()),
+    }
+
+    report
+}
+
+pub fn run_part<I: Clone, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    scope: Scope,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> PartReport {
     let part_str = format!("Part {part}");
+    let format = options.output();
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    // only the profiled part runs, so that it is all a profiler records.
+    if options
+        .profile_part
+        .is_some_and(|profiled| profiled != part)
+    {
+        return PartReport {
+            day,
+            part,
+            answer: None,
+            error: None,
+            stats: Stats::single(Duration::ZERO),
+            memory: None,
+        };
+    }
+
+    let (result, stats, memory) = run_timed(func, input, options, |result| {
+        let result = result.answer();
+        if format == Some(OutputFormat::Human) {
+            print_result(&result, &part_str, "");
+        }
+        result.is_ok()
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let result = result.answer();
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let report = PartReport {
+        day,
+        part,
+        answer: result.clone().ok().flatten(),
+        error: result.clone().err(),
+        stats,
+        memory,
+    };
+
+    match format {
+        None => {}
+        Some(OutputFormat::Human) => {
+            print_result(
+                &result,
+                &part_str,
+                &format_duration(&report.stats.mean, report.stats.samples),
+            );
+        }
+        Some(OutputFormat::Json) => {
+            println!("{}", report.to_json_line());
+        }
+    }
+
+    if let Some(error) = &report.error {
+        eprintln!("Day {day}, part {part} failed: {error}");
+    }
+
+    if let Some(answer) = &report.answer {
+        if options.submit == Some(part) {
+            submit_result(answer, scope, day, part);
+        }
     }
+
+    report
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The function is executed once, unless:
+///  1. `options.time` is set (`--time`), then it is benched according to `options.bench`: it is
+///     warmed up, then sampled for about the configured budget, within the minimum and maximum
+///     sample counts.
+///  2. `options.profile_part` is set, then it is run repeatedly so that a profiler can record it.
+///
+/// `hook` is called with the result of the first run, e.g. to print it. It returns whether the run
+/// succeeded, parts that fail are not benched.
+///
+/// With the `dhat-heap` feature, the first run is profiled and its heap usage is returned.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    options: &RunOptions,
+    hook: impl FnOnce(&T) -> bool,
+) -> (T, Stats, Option<MemoryStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, memory) = {
         let input = input.clone();
 
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        let result = func(input);
+
+        #[cfg(feature = "dhat-heap")]
+        let memory = Some(MemoryStats::from(dhat::HeapStats::get()));
+        #[cfg(not(feature = "dhat-heap"))]
+        let memory = None;
+
+        (result, memory)
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
+    let succeeded = hook(&result);
+
+    let stats = if options.profile_part.is_some() && succeeded {
+        if options.output() == Some(OutputFormat::Human) {
+            print!(" > {ANSI_ITALIC}profiling{ANSI_RESET}");
+            let _ = stdout().flush();
+        }
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+        Stats::from_samples(&profile(func, input, &base_time, &options.bench))
+            .unwrap_or(Stats::single(base_time))
+    } else if options.time && succeeded {
+        if options.output() == Some(OutputFormat::Human) {
+            print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+            let _ = stdout().flush();
+        }
+
+        Stats::from_samples(&bench(func, input, &base_time, &options.bench))
+            .unwrap_or(Stats::single(base_time))
     } else {
-        (base_time, 1)
+        Stats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    (result, stats, memory)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Benchmark a solution part and return the duration of every sample.
+///
+/// The sample count is derived from `base_time`, the duration of a first run, so that
+/// benching takes roughly the budget of `config`. Samples are preceded by an untimed warm-up phase.
+pub fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> Vec<Duration> {
+    let base_nanos = cmp::max(base_time.as_nanos(), 10);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let warmup_iterations = (config.warmup.as_nanos() / base_nanos).min(MAX_WARMUP_ITERATIONS);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input.clone())));
+    }
 
     let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+        (config.budget.as_nanos() / base_nanos).clamp(config.min_samples, config.max_samples);
 
-    let mut timers: Vec<Duration> = vec![];
+    let mut timers: Vec<Duration> = Vec::with_capacity(bench_iterations as usize);
 
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
@@ -71,19 +597,25 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    timers
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Benches a solution part over and over until [`PROFILE_DURATION`] has passed, so that a
+/// sampling profiler spends most of its time in it. Returns the samples of the last round.
+fn profile<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> Vec<Duration> {
+    let timer = Instant::now();
+
+    loop {
+        let samples = bench(&func, input.clone(), base_time, config);
+        if timer.elapsed() >= PROFILE_DURATION {
+            return samples;
+        }
+    }
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -94,12 +626,12 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(result: &Result<Option<String>, String>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -118,7 +650,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -126,44 +658,158 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(_) => {
+            if is_intermediate_result {
+                print!("{part}: {ANSI_RED}{FAILED}{ANSI_RESET}");
+            } else {
+                print!("\r");
+                println!("{part}: {ANSI_RED}{FAILED}{ANSI_RESET}{duration_str}");
+            }
+        }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Try to submit one part of the solution, via aoc-cli or the built-in client.
+///
+/// Answers that are known to be wrong from earlier submissions are not submitted again.
+/// Accepted answers are recorded, so that `cargo verify` can check against them.
+fn submit_result(answer: &str, scope: Scope, day: Day, part: u8) {
+    let Some(year) = scope.year() else {
+        eprintln!(
+            "Could not determine the year to submit for, set `year` in aoc.toml or AOC_YEAR."
+        );
+        process::exit(1);
+    };
+
+    let policy = Config::get().submit;
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    if policy == SubmitPolicy::Never {
+        eprintln!("Not submitting: submissions are disabled by the submit policy in aoc.toml.");
+        return;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    #[cfg(not(feature = "client"))]
+    if crate::template::aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    if policy == SubmitPolicy::Check {
+        if let Err(reason) = Submissions::read_from_file(scope).check(day, part, answer) {
+            eprintln!("Not submitting: {reason}");
+            return;
+        }
+    }
+
+    #[cfg(not(feature = "client"))]
+    let outcome = {
+        println!("Submitting result via aoc-cli...");
+        crate::template::aoc_cli::submit(year, day, part, answer).map_err(|e| e.to_string())
+    };
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    #[cfg(feature = "client")]
+    let outcome = {
+        println!("Submitting result...");
+        crate::template::client::submit(year, day, part, answer).map_err(|e| e.to_string())
     };
 
-    if part_submit != part {
-        return None;
+    let outcome = match outcome {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("{e}");
+            return;
+        }
+    };
+
+    if let Err(e) = Submissions::record(scope, Submission::new(day, part, answer, outcome)) {
+        eprintln!("Failed to record submission: {e}");
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    if outcome == SubmissionOutcome::Correct {
+        match answers::record(scope, day, part, answer) {
+            Ok(()) => println!("Recorded answer in \"{}\".", answers::get_path(scope, day)),
+            Err(e) => eprintln!("Failed to record answer: {e}"),
+        }
     }
+}
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{BenchConfig, InputSource, RunOptions};
+    use crate::template::report::OutputFormat;
+
+    fn options(args: &[&str]) -> RunOptions {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        RunOptions::from_args(&args, BenchConfig::default())
+    }
+
+    #[test]
+    fn reads_input_source() {
+        assert_eq!(options(&["01"]).input, InputSource::Puzzle);
+        assert_eq!(options(&["01", "--input", "-"]).input, InputSource::Stdin);
+        assert_eq!(
+            options(&["01", "--time", "--input", "other/01.txt"]).input,
+            InputSource::File("other/01.txt".into())
+        );
+    }
+
+    #[test]
+    fn reads_profiled_part() {
+        assert_eq!(options(&["01"]).profile_part, None);
+        assert_eq!(
+            options(&["01", "--profile-part", "2"]).profile_part,
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn reads_bench_config() {
+        assert_eq!(options(&["01", "--time"]).bench, BenchConfig::default());
+
+        let bench = options(&["01", "--bench-budget", "2s", "--max-samples", "50"]).bench;
+        assert_eq!(bench.budget, Duration::from_secs(2));
+        assert_eq!(bench.max_samples, 50);
+        assert_eq!(bench.min_samples, BenchConfig::default().min_samples);
+
+        // flags override the defaults passed in, e.g. from `aoc.toml`.
+        let defaults = BenchConfig {
+            warmup: Duration::ZERO,
+            ..BenchConfig::default()
+        };
+        let args = [
+            "01".to_string(),
+            "--min-samples".to_string(),
+            "1".to_string(),
+        ];
+        let bench = RunOptions::from_args(&args, defaults).bench;
+        assert_eq!(bench.warmup, Duration::ZERO);
+        assert_eq!(bench.min_samples, 1);
+    }
+
+    #[test]
+    fn converts_to_args() {
+        let options = RunOptions {
+            time: true,
+            format: OutputFormat::Json,
+            input: InputSource::File("other/01.txt".into()),
+            bench: BenchConfig {
+                budget: Duration::from_millis(1500),
+                warmup: Duration::ZERO,
+                min_samples: 5,
+                max_samples: 50,
+            },
+            ..RunOptions::default()
+        };
+
+        let args = options.to_args();
+        let parsed = RunOptions::from_args(&args, BenchConfig::default());
+
+        assert_eq!(parsed.time, options.time);
+        assert_eq!(parsed.format, options.format);
+        assert_eq!(parsed.input, options.input);
+        assert_eq!(parsed.bench, options.bench);
+        assert_eq!(RunOptions::default().to_args(), Vec::<String>::new());
+    }
 }
@@ -0,0 +1,249 @@
+/// Summary statistics over the samples collected while running a solution part.
+use std::{collections::HashMap, time::Duration};
+
+use tinyjson::JsonValue;
+
+/// Samples whose modified z-score exceeds this value are considered outliers.
+/// See: Iglewicz and Hoaglin, "How to Detect and Handle Outliers" (1993).
+const OUTLIER_Z_SCORE: f64 = 3.5;
+
+/// Scales the median absolute deviation so it is comparable to a standard deviation.
+const MAD_SCALE: f64 = 0.6745;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of collected samples, including outliers.
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u128,
+    /// Arithmetic mean of all samples that are not outliers.
+    pub mean: Duration,
+    /// Standard deviation of all samples that are not outliers.
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Statistics for a part that was executed exactly once.
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            samples: 1,
+            outliers: 0,
+            mean: duration,
+            stddev: Duration::ZERO,
+            min: duration,
+            max: duration,
+            median: duration,
+            p95: duration,
+        }
+    }
+
+    /// Computes statistics from a non-empty set of samples.
+    ///
+    /// Order statistics (min, max, median, p95) are computed over every sample, while
+    /// the mean and standard deviation exclude outliers detected via the median absolute deviation.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted: Vec<f64> = samples.iter().map(as_nanos_f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let min = *sorted.first()?;
+        let max = *sorted.last()?;
+        let median = percentile(&sorted, 50.0);
+
+        let mut deviations: Vec<f64> = sorted.iter().map(|x| (x - median).abs()).collect();
+        deviations.sort_unstable_by(f64::total_cmp);
+        let mad = percentile(&deviations, 50.0);
+
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| !is_outlier(*x, median, mad))
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let len = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / len;
+        let variance = kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len;
+
+        Some(Self {
+            samples: sorted.len() as u128,
+            outliers: (sorted.len() - kept.len()) as u128,
+            mean: from_nanos_f64(mean),
+            stddev: from_nanos_f64(variance.sqrt()),
+            min: from_nanos_f64(min),
+            max: from_nanos_f64(max),
+            median: from_nanos_f64(median),
+            p95: from_nanos_f64(percentile(&sorted, 95.0)),
+        })
+    }
+}
+
+/// Heap usage of a single run of a solution part, as measured by dhat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Bytes allocated over the whole run.
+    pub total_bytes: u64,
+    /// Number of allocations over the whole run.
+    pub total_blocks: u64,
+    /// Bytes that were allocated at the peak of the run.
+    pub peak_bytes: u64,
+    /// Number of allocations that were live at the peak of the run.
+    pub peak_blocks: u64,
+}
+
+#[cfg(feature = "dhat-heap")]
+impl From<dhat::HeapStats> for MemoryStats {
+    fn from(value: dhat::HeapStats) -> Self {
+        Self {
+            total_bytes: value.total_bytes,
+            total_blocks: value.total_blocks,
+            peak_bytes: value.max_bytes as u64,
+            peak_blocks: value.max_blocks as u64,
+        }
+    }
+}
+
+impl From<&MemoryStats> for JsonValue {
+    fn from(value: &MemoryStats) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let number = |x: u64| JsonValue::Number(x as f64);
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("total_bytes".into(), number(value.total_bytes));
+        map.insert("total_blocks".into(), number(value.total_blocks));
+        map.insert("peak_bytes".into(), number(value.peak_bytes));
+        map.insert("peak_blocks".into(), number(value.peak_blocks));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u64)
+                .ok_or(format!("Expected memory.{key} to be a number."))
+        };
+
+        Ok(Self {
+            total_bytes: number("total_bytes")?,
+            total_blocks: number("total_blocks")?,
+            peak_bytes: number("peak_bytes")?,
+            peak_blocks: number("peak_blocks")?,
+        })
+    }
+}
+
+/// Parses the optional `memory` of a JSON object. Records written before memory was measured
+/// have no `memory` key.
+pub fn optional_memory(json: &HashMap<String, JsonValue>) -> Result<Option<MemoryStats>, String> {
+    match json.get("memory") {
+        None => Ok(None),
+        Some(v) if v.is_null() => Ok(None),
+        Some(v) => MemoryStats::try_from(v).map(Some),
+    }
+}
+
+fn is_outlier(x: f64, median: f64, mad: f64) -> bool {
+    // a MAD of zero means that at least half of the samples are identical,
+    // in which case there is no meaningful spread to compare against.
+    mad > 0.0 && MAD_SCALE * (x - median).abs() / mad > OUTLIER_Z_SCORE
+}
+
+/// Linearly interpolated percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+
+    #[allow(clippy::cast_precision_loss)]
+    let weight = rank - lower as f64;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn as_nanos_f64(duration: &Duration) -> f64 {
+    duration.as_nanos() as f64
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos_f64(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn computes_stats_for_odd_samples() {
+        let stats = Stats::from_samples(&nanos(&[30, 10, 20])).unwrap();
+        assert_eq!(stats.samples, 3);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.mean, Duration::from_nanos(20));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(20));
+        assert_eq!(stats.stddev, Duration::from_nanos(8));
+    }
+
+    #[test]
+    fn computes_median_for_even_samples() {
+        let stats = Stats::from_samples(&nanos(&[40, 10, 20, 30])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(25));
+    }
+
+    #[test]
+    fn computes_p95() {
+        let samples: Vec<u64> = (1..=101).collect();
+        let stats = Stats::from_samples(&nanos(&samples)).unwrap();
+        assert_eq!(stats.p95, Duration::from_nanos(96));
+    }
+
+    #[test]
+    fn rejects_outliers_from_mean() {
+        let stats = Stats::from_samples(&nanos(&[100, 101, 99, 100, 102, 98, 100, 5000])).unwrap();
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+        assert_eq!(stats.max, Duration::from_nanos(5000));
+    }
+
+    #[test]
+    fn keeps_identical_samples() {
+        let stats = Stats::from_samples(&nanos(&[50, 50, 50, 50, 51])).unwrap();
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.median, Duration::from_nanos(50));
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = Stats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats, Stats::single(Duration::from_nanos(42)));
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
@@ -0,0 +1,260 @@
+/// Module that collects the progress of every day of a year, for `cargo status`.
+use std::{collections::HashMap, fs};
+
+use tinyjson::JsonValue;
+
+use crate::template::answers::Answers;
+use crate::template::examples::{example_files, Manifest};
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day, Scope, Year, ANSI_BOLD, ANSI_RESET};
+
+/// The files and results that exist for a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: Day,
+    /// The module file `src/bin/<day>.rs` exists.
+    pub scaffolded: bool,
+    /// The input file exists and is not empty.
+    pub input: bool,
+    /// Number of example files that are not empty.
+    pub examples: usize,
+    /// Number of `#[test]` functions in the module file, and of the cases generated by
+    /// `example_tests!`.
+    pub tests: usize,
+    /// Both parts have stored timings.
+    pub timed: bool,
+    /// Number of parts with an accepted answer.
+    pub stars: u8,
+}
+
+impl DayStatus {
+    /// Creates the status of a day that has not been started.
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            scaffolded: false,
+            input: false,
+            examples: 0,
+            tests: 0,
+            timed: false,
+            stars: 0,
+        }
+    }
+
+    pub fn read(year: Year, day: Day, timings: &Timings) -> Self {
+        let scope = Scope::resolve(year, day);
+        let module = fs::read_to_string(scope.bin_path(day)).ok();
+        let answers = Answers::read(scope, day);
+
+        let examples = example_files(scope, day)
+            .into_iter()
+            .filter(|file| is_non_empty(&scope.data_path("examples", &file.name(day))))
+            .count();
+
+        DayStatus {
+            day,
+            scaffolded: module.is_some(),
+            input: is_non_empty(&scope.input_path(day)),
+            examples,
+            tests: module.as_deref().map_or(0, |module| {
+                count_tests(module, || Manifest::read(scope, day))
+            }),
+            timed: timings.is_day_complete(day),
+            #[allow(clippy::cast_possible_truncation)]
+            stars: (1..=2).filter(|part| answers.get(*part).is_some()).count() as u8,
+        }
+    }
+}
+
+/// Collects the status of every day of `year`.
+pub fn read_all(year: Year) -> Vec<DayStatus> {
+    let timings = Timings::read_from_file(Scope::for_year(year));
+    all_days()
+        .map(|day| DayStatus::read(year, day, &timings))
+        .collect()
+}
+
+pub(crate) fn is_non_empty(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+fn count_tests(module: &str, manifest: impl FnOnce() -> Result<Manifest, String>) -> usize {
+    let tests = module
+        .lines()
+        .filter(|line| line.trim() == "#[test]")
+        .count();
+
+    if !module.contains("example_tests!") {
+        return tests;
+    }
+
+    // a test is generated per case, or a single failing one if the manifest can not be read.
+    tests + manifest().map_or(1, |manifest| manifest.examples.len())
+}
+
+pub fn total_stars(days: &[DayStatus]) -> usize {
+    days.iter().map(|day| usize::from(day.stars)).sum()
+}
+
+/// Renders a grid with one row per day.
+pub fn format_table(year: Year, days: &[DayStatus]) -> String {
+    let check = |value: bool| if value { "✓" } else { "-" };
+    let count = |value: usize| {
+        if value > 0 {
+            value.to_string()
+        } else {
+            "-".into()
+        }
+    };
+
+    let mut lines: Vec<String> = vec![
+        format!("{ANSI_BOLD}Advent of Code {year}{ANSI_RESET}"),
+        String::new(),
+        format!(
+            "{:<4} {:<7} {:<6} {:<9} {:<6} {:<6} {}",
+            "Day", "Module", "Input", "Examples", "Tests", "Timed", "Stars"
+        ),
+    ];
+
+    for status in days {
+        lines.push(
+            format!(
+                "{:<4} {:<7} {:<6} {:<9} {:<6} {:<6} {}",
+                status.day.to_string(),
+                check(status.scaffolded),
+                check(status.input),
+                count(status.examples),
+                count(status.tests),
+                check(status.timed),
+                "★".repeat(status.stars.into()),
+            )
+            .trim_end()
+            .to_string(),
+        );
+    }
+
+    lines.push(String::new());
+    lines.push(format!(
+        "{ANSI_BOLD}Stars:{ANSI_RESET} {}/{}",
+        total_stars(days),
+        days.len() * 2
+    ));
+
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&DayStatus> for JsonValue {
+    fn from(value: &DayStatus) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let number = |n: usize| JsonValue::Number(n as f64);
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert(
+            "day".into(),
+            JsonValue::Number(value.day.into_inner().into()),
+        );
+        map.insert("scaffolded".into(), JsonValue::Boolean(value.scaffolded));
+        map.insert("input".into(), JsonValue::Boolean(value.input));
+        map.insert("examples".into(), number(value.examples));
+        map.insert("tests".into(), number(value.tests));
+        map.insert("timed".into(), JsonValue::Boolean(value.timed));
+        map.insert("stars".into(), JsonValue::Number(value.stars.into()));
+        JsonValue::Object(map)
+    }
+}
+
+/// Serializes the status of `days` to a single JSON object.
+pub fn to_json(year: Year, days: &[DayStatus]) -> String {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert("year".into(), JsonValue::Number(year.into_inner().into()));
+    #[allow(clippy::cast_precision_loss)]
+    map.insert("stars".into(), JsonValue::Number(total_stars(days) as f64));
+    map.insert(
+        "days".into(),
+        JsonValue::Array(days.iter().map(JsonValue::from).collect()),
+    );
+
+    JsonValue::Object(map)
+        .stringify()
+        .expect("status should be serializable")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use tinyjson::JsonValue;
+
+    use super::{count_tests, format_table, to_json, DayStatus};
+    use crate::{day, template::Year};
+
+    fn statuses() -> Vec<DayStatus> {
+        vec![
+            DayStatus {
+                day: day!(1),
+                scaffolded: true,
+                input: true,
+                examples: 2,
+                tests: 2,
+                timed: true,
+                stars: 2,
+            },
+            DayStatus {
+                day: day!(2),
+                scaffolded: true,
+                stars: 1,
+                ..DayStatus::new(day!(2))
+            },
+            DayStatus::new(day!(3)),
+        ]
+    }
+
+    #[test]
+    fn counts_tests() {
+        let module = "#[cfg(test)]\nmod tests {\n    #[test]\n    fn a() {}\n\n    #[test]\n    fn b() {}\n}\n";
+        let no_manifest = || Err("no manifest".to_string());
+        assert_eq!(count_tests(module, no_manifest), 2);
+        assert_eq!(count_tests("fn main() {}", no_manifest), 0);
+
+        let module = "#[cfg(test)]\nmod tests {\n    #[test]\n    fn a() {}\n\n    advent_of_code::example_tests!();\n}\n";
+        let manifest = || "13.txt 1 295\n13-1.txt 2 3417 slow\n".parse();
+        assert_eq!(count_tests(module, manifest), 3);
+        assert_eq!(count_tests(module, no_manifest), 2);
+    }
+
+    #[test]
+    fn formats_table() {
+        let table = format_table(Year::new(2020).unwrap(), &statuses());
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(
+            lines[2],
+            "Day  Module  Input  Examples  Tests  Timed  Stars"
+        );
+        assert_eq!(lines[3], "01   ✓       ✓      2         2      ✓      ★★");
+        assert_eq!(lines[4], "02   ✓       -      -         -      -      ★");
+        assert_eq!(lines[5], "03   -       -      -         -      -");
+        assert!(lines[7].ends_with("3/6"));
+    }
+
+    #[test]
+    fn serializes_json() {
+        let json: JsonValue = to_json(Year::new(2020).unwrap(), &statuses())
+            .parse()
+            .unwrap();
+        let json: &HashMap<String, JsonValue> = json.get().unwrap();
+
+        assert_eq!(json["year"], JsonValue::Number(2020.0));
+        assert_eq!(json["stars"], JsonValue::Number(3.0));
+
+        let days: &Vec<JsonValue> = json["days"].get().unwrap();
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0]["examples"], JsonValue::Number(2.0));
+        assert_eq!(days[1]["scaffolded"], JsonValue::Boolean(true));
+        assert_eq!(days[2]["stars"], JsonValue::Number(0.0));
+    }
+}
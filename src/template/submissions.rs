@@ -0,0 +1,285 @@
+/// Module that keeps a log of submitted answers and the responses they received.
+use std::{collections::HashMap, fs, io::Error, path::Path};
+
+use tinyjson::JsonValue;
+
+use crate::template::aoc_cli::SubmissionOutcome;
+use crate::template::timings::unix_timestamp;
+use crate::template::{Day, Scope};
+
+fn get_path(scope: Scope) -> String {
+    Path::new(".")
+        .join(scope.data_dir())
+        .join("submissions.json")
+        .display()
+        .to_string()
+}
+
+/// A single answer that was submitted via aoc-cli.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+    /// Seconds since the unix epoch at which the answer was submitted.
+    pub timestamp: Option<u64>,
+}
+
+impl Submission {
+    pub fn new(day: Day, part: u8, answer: &str, outcome: SubmissionOutcome) -> Self {
+        Self {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: unix_timestamp(),
+        }
+    }
+}
+
+/// Log of all submissions, in the order they were made.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn store_file(&self, scope: Scope) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        fs::create_dir_all(scope.data_dir())?;
+        let mut file = fs::File::create(get_path(scope))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the log from a JSON file. If not present, returns an empty log.
+    pub fn read_from_file(scope: Scope) -> Self {
+        let s = fs::read_to_string(get_path(scope));
+
+        match s.map(|s| Submissions::try_from(s.as_str())) {
+            Ok(Ok(submissions)) => submissions,
+            Ok(Err(e)) => {
+                eprintln!("{e}");
+                Submissions::default()
+            }
+            Err(_) => Submissions::default(),
+        }
+    }
+
+    /// Appends `submission` to the log file.
+    pub fn record(scope: Scope, submission: Submission) -> Result<(), Error> {
+        let mut submissions = Self::read_from_file(scope);
+        submissions.data.push(submission);
+        submissions.store_file(scope)
+    }
+
+    /// Returns why `answer` should not be submitted for `part`, if it is known to be wrong.
+    ///
+    /// An answer is known to be wrong if it was rejected before, or if it lies outside the
+    /// bounds given by earlier answers that were too high or too low.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), String> {
+        let submissions = self
+            .data
+            .iter()
+            .filter(|s| s.day == day && s.part == part && s.outcome.is_wrong());
+
+        let value = answer.trim().parse::<i128>().ok();
+
+        for submission in submissions {
+            if submission.answer == answer {
+                return Err(format!(
+                    "{answer} was already submitted and was rejected ({}).",
+                    submission.outcome
+                ));
+            }
+
+            let (Some(value), Ok(bound)) = (value, submission.answer.parse::<i128>()) else {
+                continue;
+            };
+
+            match submission.outcome {
+                SubmissionOutcome::TooHigh if value >= bound => {
+                    return Err(format!("{answer} is too high, {bound} already was."));
+                }
+                SubmissionOutcome::TooLow if value <= bound => {
+                    return Err(format!("{answer} is too low, {bound} already was."));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for Submissions {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = value.parse::<JsonValue>().or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.to_string()),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            value
+                .timestamp
+                .map_or(JsonValue::Null, |t| JsonValue::Number(t as f64)),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected submission to be an object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("expected `submission.{key}` to be a string."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|n| *n as u64)
+        };
+
+        Ok(Submission {
+            day: string("day")?
+                .parse()
+                .map_err(|_| "expected `submission.day` to be a Day struct.")?,
+            part: number("part")
+                .and_then(|n| u8::try_from(n).ok())
+                .ok_or("expected `submission.part` to be a number.")?,
+            answer: string("answer")?.clone(),
+            outcome: string("outcome")?
+                .parse()
+                .map_err(|_| "expected `submission.outcome` to be a known outcome.")?,
+            timestamp: number("timestamp"),
+        })
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Submission, Submissions};
+    use crate::day;
+    use crate::template::aoc_cli::SubmissionOutcome;
+
+    fn submission(part: u8, answer: &str, outcome: SubmissionOutcome) -> Submission {
+        Submission {
+            day: day!(1),
+            part,
+            answer: answer.into(),
+            outcome,
+            timestamp: Some(1_700_000_000),
+        }
+    }
+
+    fn get_mock_submissions() -> Submissions {
+        Submissions {
+            data: vec![
+                submission(1, "50", SubmissionOutcome::TooHigh),
+                submission(1, "10", SubmissionOutcome::TooLow),
+                submission(1, "abc", SubmissionOutcome::Incorrect),
+                submission(1, "20", SubmissionOutcome::RateLimited),
+                submission(2, "42", SubmissionOutcome::Correct),
+            ],
+        }
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let submissions = get_mock_submissions();
+        assert!(submissions.check(day!(1), 1, "abc").is_err());
+        assert!(submissions.check(day!(1), 1, "50").is_err());
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let submissions = get_mock_submissions();
+        assert!(submissions.check(day!(1), 1, "51").is_err());
+        assert!(submissions.check(day!(1), 1, "9").is_err());
+        assert!(submissions.check(day!(1), 1, "10").is_err());
+    }
+
+    #[test]
+    fn allows_unknown_answers() {
+        let submissions = get_mock_submissions();
+        assert!(submissions.check(day!(1), 1, "11").is_ok());
+        assert!(submissions.check(day!(1), 1, "49").is_ok());
+        // rate limited answers were never checked.
+        assert!(submissions.check(day!(1), 1, "20").is_ok());
+        assert!(submissions.check(day!(1), 2, "5").is_ok());
+        assert!(submissions.check(day!(2), 1, "50").is_ok());
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = get_mock_submissions();
+        let json = JsonValue::from(&submissions).stringify().unwrap();
+        let parsed = Submissions::try_from(json.as_str()).unwrap();
+        assert_eq!(parsed.data, submissions.data);
+    }
+
+    #[test]
+    fn handles_empty_submissions() {
+        let parsed = Submissions::try_from(r#"{ "data": [] }"#).unwrap();
+        assert!(parsed.data.is_empty());
+    }
+
+    #[test]
+    fn fails_for_malformed_submissions() {
+        let json = r#"{ "data": [{ "day": "01", "part": 1, "answer": "1", "outcome": "maybe" }] }"#;
+        assert!(Submissions::try_from(json).is_err());
+    }
+}
@@ -1,17 +1,138 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::Path,
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::stats::{optional_memory, MemoryStats};
+use crate::template::{Day, Scope};
+
+/// Version of the JSON schema written by [`Timings::store_file`].
+/// Files without a `version` key use the legacy schema that stored display strings.
+pub const SCHEMA_VERSION: u8 = 2;
+
+/// Represents the benchmark result of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Mean execution time in nanoseconds.
+    pub nanos: f64,
+    /// Number of samples the mean was computed from. Unknown for migrated timings.
+    pub samples: Option<u128>,
+    /// Heap usage of a single run, if it was measured via `cargo time --memory`.
+    pub memory: Option<MemoryStats>,
+}
+
+impl PartTiming {
+    /// Formats the execution time like `Duration`'s debug output, e.g. `74.1µs`.
+    pub fn format_duration(&self) -> String {
+        format_nanos(self.nanos)
+    }
+}
+
+/// Why a solution was stopped before it finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Abort {
+    /// The solution ran longer than the configured timeout.
+    Timeout,
+    /// The solution exceeded the configured memory limit.
+    OutOfMemory,
+}
+
+impl Abort {
+    fn key(self) -> &'static str {
+        match self {
+            Abort::Timeout => "timeout",
+            Abort::OutOfMemory => "oom",
+        }
+    }
+}
+
+impl std::fmt::Display for Abort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Abort::Timeout => f.write_str("TIMEOUT"),
+            Abort::OutOfMemory => f.write_str("OOM"),
+        }
+    }
+}
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+impl FromStr for Abort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Abort::Timeout, Abort::OutOfMemory]
+            .into_iter()
+            .find(|abort| abort.key() == s)
+            .ok_or(format!("Unknown abort reason `{s}`."))
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Timing of the parse stage shared by both parts, if the solution has one.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+    /// Parts that returned an error instead of an answer.
+    pub failed: Vec<u8>,
+    /// Set if the solution was killed, parts that did not finish have no timing.
+    pub aborted: Option<Abort>,
     pub total_nanos: f64,
+    /// Seconds since the unix epoch at which the day was benched.
+    pub timestamp: Option<u64>,
+    pub git_revision: Option<String>,
+    /// Cargo profile the solution was built with, e.g. `release`.
+    pub profile: Option<String>,
+}
+
+impl Timing {
+    /// Creates an empty timing for `day` without any parts or metadata.
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            failed: vec![],
+            aborted: None,
+            total_nanos: 0_f64,
+            timestamp: None,
+            git_revision: None,
+            profile: None,
+        }
+    }
+
+    /// Sets the part timing for `part` and adds it to the total. Part `0` is the parse stage.
+    pub fn set_part(&mut self, part: u8, timing: PartTiming) {
+        let slot = match part {
+            0 => &mut self.parse,
+            1 => &mut self.part_1,
+            2 => &mut self.part_2,
+            _ => return,
+        };
+
+        self.total_nanos += timing.nanos;
+        *slot = Some(timing);
+    }
+
+    /// Marks `part` as failed.
+    pub fn set_failed(&mut self, part: u8) {
+        if !self.failed.contains(&part) {
+            self.failed.push(part);
+            self.failed.sort_unstable();
+        }
+    }
+
+    pub fn is_failed(&self, part: u8) -> bool {
+        self.failed.contains(&part)
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -22,25 +143,29 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to the JSON file of `scope`.
+    pub fn store_file(&self, scope: Scope) -> Result<(), Error> {
+        let path = scope.timings_path();
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(path)?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from);
+    /// Rehydrate timings from the JSON file of `scope`. If not present, returns empty timings.
+    /// Files written with an older schema are migrated to the current one.
+    pub fn read_from_file(scope: Scope) -> Self {
+        let s = fs::read_to_string(scope.timings_path());
 
-        match s {
-            Ok(timings) => timings,
-            Err(e) => {
+        match s.map(Timings::try_from) {
+            Ok(Ok(timings)) => timings,
+            Ok(Err(e)) => {
                 eprintln!("{e}");
                 Timings::default()
             }
+            Err(_) => Timings::default(),
         }
     }
 
@@ -58,7 +183,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| t.day);
         Timings { data }
     }
 
@@ -74,12 +199,80 @@ impl Timings {
     }
 }
 
+/// Parses a duration formatted via `{:?}` (e.g. `74.13ns` or `1.2s`) into nanoseconds.
+pub fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let parse_to_float = |postfix: &str| s.split(postfix).next()?.parse::<f64>().ok();
+
+    match s {
+        s if s.contains("ns") => parse_to_float("ns"),
+        s if s.contains("µs") => parse_to_float("µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float("ms").map(|x| x * 1_000_000_f64),
+        _ => parse_to_float("s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
+/// Formats nanoseconds like `Duration`'s debug output with one decimal, e.g. `74.1µs`.
+pub fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1}{}", UNITS[unit])
+}
+
+/// Seconds since the unix epoch.
+pub fn unix_timestamp() -> Option<u64> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
+/// Short hash of the checked out git commit, if the working directory is a git repository.
+pub fn git_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let revision = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!revision.is_empty()).then_some(revision)
+}
+
 /* -------------------------------------------------------------------------- */
 
+fn optional<T>(value: Option<T>, f: impl FnOnce(T) -> JsonValue) -> JsonValue {
+    value.map_or(JsonValue::Null, f)
+}
+
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("version".into(), JsonValue::Number(SCHEMA_VERSION.into()));
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -95,25 +288,86 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let version = match json.get("version") {
+            None => 1,
+            Some(v) => v
+                .get::<f64>()
+                .map(|x| *x as u8)
+                .ok_or("expected `json.version` to be a number.")?,
+        };
+
+        let parse: fn(&JsonValue) -> Result<Timing, String> = match version {
+            1 => Timing::try_from_legacy,
+            SCHEMA_VERSION => |v| Timing::try_from(v),
+            _ => return Err(format!("unsupported timings version `{version}`.")),
+        };
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
         Ok(Timings {
-            data: json_data
-                .iter()
-                .map(Timing::try_from)
-                .collect::<Result<_, _>>()?,
+            data: json_data.iter().map(parse).collect::<Result<_, _>>()?,
         })
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "samples".into(),
+            optional(value.samples, |x| JsonValue::Number(x as f64)),
+        );
+        map.insert(
+            "memory".into(),
+            optional(value.memory.as_ref(), JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part timing.nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = json
+            .get("samples")
+            .map(|v| v.get::<f64>().map(|x| *x as u128))
+            .ok_or("Expected part timing.samples to be null or a number.")?;
+
+        Ok(PartTiming {
+            nanos,
+            samples,
+            memory: optional_memory(json)?,
+        })
+    }
+}
+
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
@@ -121,29 +375,67 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
+        map.insert(
+            "parse".into(),
+            optional(value.parse.as_ref(), JsonValue::from),
+        );
         map.insert(
             "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            optional(value.part_1.as_ref(), JsonValue::from),
         );
-
         map.insert(
             "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            optional(value.part_2.as_ref(), JsonValue::from),
+        );
+        map.insert(
+            "failed".into(),
+            JsonValue::Array(
+                value
+                    .failed
+                    .iter()
+                    .map(|part| JsonValue::Number((*part).into()))
+                    .collect(),
+            ),
+        );
+
+        map.insert(
+            "aborted".into(),
+            optional(value.aborted, |x| JsonValue::String(x.key().into())),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            optional(value.timestamp, |x| JsonValue::Number(x as f64)),
+        );
+        map.insert(
+            "git_revision".into(),
+            optional(value.git_revision.clone(), JsonValue::String),
+        );
+        map.insert(
+            "profile".into(),
+            optional(value.profile.clone(), JsonValue::String),
         );
 
         JsonValue::Object(map)
     }
 }
 
+fn parse_day(json: &HashMap<String, JsonValue>) -> Result<Day, String> {
+    Ok(json
+        .get("day")
+        .and_then(|v| v.get::<String>())
+        .and_then(|day| Day::from_str(day).ok())
+        .ok_or("Expected timing.day to be a Day struct.")?)
+}
+
+fn parse_total_nanos(json: &HashMap<String, JsonValue>) -> Result<f64, String> {
+    Ok(json
+        .get("total_nanos")
+        .and_then(|v| v.get::<f64>().copied())
+        .ok_or("Expected timing.total_nanos to be a number.")?)
+}
+
 impl TryFrom<&JsonValue> for Timing {
     type Error = String;
 
@@ -152,32 +444,99 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
+        let part = |key: &str| -> Result<Option<PartTiming>, String> {
+            match json.get(key) {
+                Some(v) if v.is_null() => Ok(None),
+                Some(v) => PartTiming::try_from(v).map(Some),
+                None => Err(format!("Expected timing.{key} to be null or an object.")),
+            }
+        };
+
+        let string = |key: &str| -> Result<Option<String>, String> {
+            json.get(key)
+                .map(|v| v.get::<String>().cloned())
+                .ok_or(format!("Expected timing.{key} to be null or string."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .map(|v| v.get::<f64>().map(|x| *x as u64))
+            .ok_or("Expected timing.timestamp to be null or a number.")?;
+
+        // timings stored before failures were tracked have no `failed` key.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let failed = match json.get("failed") {
+            None => vec![],
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .and_then(|parts| {
+                    parts
+                        .iter()
+                        .map(|part| part.get::<f64>().map(|x| *x as u8))
+                        .collect()
+                })
+                .ok_or("Expected timing.failed to be an array of numbers.")?,
+        };
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        // timings stored before runs could be aborted have no `aborted` key.
+        let aborted = match json.get("aborted") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.aborted to be null or string.")?
+                    .parse()?,
+            ),
+        };
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        Ok(Timing {
+            day: parse_day(json)?,
+            // timings stored before parse stages were supported have no `parse` key.
+            parse: json.get("parse").map_or(Ok(None), |_| part("parse"))?,
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+            failed,
+            aborted,
+            total_nanos: parse_total_nanos(json)?,
+            timestamp,
+            git_revision: string("git_revision")?,
+            profile: string("profile")?,
+        })
+    }
+}
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+impl Timing {
+    /// Parses a timing stored with the legacy schema, where parts were display strings like `"74.13ns"`.
+    fn try_from_legacy(value: &JsonValue) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
+
+        let part = |key: &str| -> Result<Option<PartTiming>, String> {
+            let value = json
+                .get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected timing.{key} to be null or string."))?;
+
+            value
+                .map(|s| {
+                    parse_duration(s)
+                        .map(|nanos| PartTiming {
+                            nanos,
+                            samples: None,
+                            memory: None,
+                        })
+                        .ok_or(format!("Could not parse timing.{key} `{s}` as a duration."))
+                })
+                .transpose()
+        };
 
         Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+            total_nanos: parse_total_nanos(json)?,
+            ..Timing::new(parse_day(json)?)
         })
     }
 }
@@ -188,28 +547,38 @@ impl TryFrom<&JsonValue> for Timing {
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
+
+    pub fn part(nanos: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos,
+            samples: Some(10),
+            memory: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    part_1: part(1e+10),
+                    part_2: part(2e+10),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    parse: part(5e+9),
+                    part_1: part(3e+10),
+                    part_2: part(4e+10),
+                    total_nanos: 7.5e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
+                    part_1: part(4e+10),
                     part_2: None,
+                    failed: vec![2],
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -220,14 +589,36 @@ mod tests {
 
         #[test]
         fn handles_json_timings() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 10 }, "part_2": null, "total_nanos": 1000000, "timestamp": 1700000000, "git_revision": "abc1234", "profile": "release" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            let part_1 = timing.part_1.clone().unwrap();
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, Some(10));
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.parse, None);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+            assert_eq!(timing.timestamp, Some(1_700_000_000));
+            assert_eq!(timing.git_revision, Some("abc1234".into()));
+            assert_eq!(timing.profile, Some("release".into()));
+        }
+
+        #[test]
+        fn migrates_legacy_timings() {
             let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.clone().unwrap();
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, None);
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.timestamp, None);
+            assert_eq!(timing.git_revision, None);
         }
 
         #[test]
@@ -244,16 +635,34 @@ mod tests {
             Timings::try_from(json).unwrap();
         }
 
+        #[test]
+        #[should_panic]
+        fn panics_for_unknown_versions() {
+            let json = r#"{ "version": 99, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
             let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_legacy_parts_in_current_schema() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "timestamp": null, "git_revision": null, "profile": null }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
     }
 
     mod serialization {
-        use super::get_mock_timings;
+        use super::{get_mock_timings, part};
+        use crate::{
+            day,
+            template::timings::{Abort, Timing, Timings},
+        };
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -261,11 +670,10 @@ mod tests {
         fn serializes_timings() {
             let timings = get_mock_timings();
             let value = JsonValue::try_from(timings).unwrap();
+            let map = value.get::<HashMap<String, JsonValue>>().unwrap();
+            assert_eq!(map.get("version").unwrap().get::<f64>(), Some(&2.0));
             assert_eq!(
-                value
-                    .get::<HashMap<String, JsonValue>>()
-                    .unwrap()
-                    .get("data")
+                map.get("data")
                     .unwrap()
                     .get::<Vec<JsonValue>>()
                     .unwrap()
@@ -273,9 +681,48 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn roundtrips_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data.len(), 3);
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[0].parse, None);
+            assert_eq!(parsed.data[1].parse, timings.data[1].parse);
+            assert_eq!(parsed.data[2].part_2, None);
+            assert_eq!(parsed.data[0].failed, vec![]);
+            assert_eq!(parsed.data[2].failed, vec![2]);
+            assert_eq!(parsed.data[2].aborted, None);
+        }
+
+        #[test]
+        fn roundtrips_aborted_timings() {
+            let timings = Timings {
+                data: vec![
+                    Timing {
+                        part_1: part(1e+9),
+                        aborted: Some(Abort::Timeout),
+                        total_nanos: 1e+9,
+                        ..Timing::new(day!(8))
+                    },
+                    Timing {
+                        aborted: Some(Abort::OutOfMemory),
+                        ..Timing::new(day!(14))
+                    },
+                ],
+            };
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].aborted, Some(Abort::Timeout));
+            assert_eq!(parsed.data[0].part_1, part(1e+9));
+            assert_eq!(parsed.data[1].aborted, Some(Abort::OutOfMemory));
+        }
     }
 
     mod is_day_complete {
+        use super::part;
         use crate::{
             day,
             template::timings::{Timing, Timings},
@@ -285,42 +732,36 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
+                    part_1: part(1_000_000_f64),
+                    part_2: part(2_000_000_f64),
+                    total_nanos: 3_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
+                    part_1: part(1_000_000_f64),
+                    total_nanos: 1_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![Timing::new(day!(1))],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -336,12 +777,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(3))],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -356,12 +792,7 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(2))],
             };
             let merged = timings.merge(&other);
 
@@ -388,4 +819,15 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+
+    mod format_nanos {
+        use crate::template::timings::format_nanos;
+
+        #[test]
+        fn formats_like_duration() {
+            assert_eq!(format_nanos(74.13), "74.0ns");
+            assert_eq!(format_nanos(74_130.0), "74.1µs");
+            assert_eq!(format_nanos(2e9), "2.0s");
+        }
+    }
 }
@@ -0,0 +1,275 @@
+/// Module that parses the subset of TOML used by `aoc.toml`: `[table]` headers and
+/// `key = value` pairs with strings, integers, floats and booleans on a single line.
+///
+/// `build.rs` includes this file as well, so it must not depend on the rest of the crate or on
+/// any dependency.
+use std::collections::HashMap;
+
+/// A value of a `key = value` pair.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+}
+
+/// The `key = value` pairs of a table. Keys before the first header belong to the table `""`.
+pub struct Table {
+    pub name: String,
+    pub values: HashMap<String, Value>,
+}
+
+impl Table {
+    fn new(name: &str) -> Self {
+        Table {
+            name: name.into(),
+            values: HashMap::new(),
+        }
+    }
+
+    fn qualified(&self, key: &str) -> String {
+        if self.name.is_empty() {
+            key.into()
+        } else {
+            format!("{}.{key}", self.name)
+        }
+    }
+
+    pub fn string(&mut self, key: &str) -> Result<Option<String>, String> {
+        match self.values.remove(key) {
+            None => Ok(None),
+            Some(Value::String(value)) => Ok(Some(value)),
+            Some(_) => Err(format!("`{}` must be a string.", self.qualified(key))),
+        }
+    }
+
+    pub fn integer(&mut self, key: &str) -> Result<Option<u64>, String> {
+        match self.values.remove(key) {
+            None => Ok(None),
+            Some(Value::Integer(value)) if value >= 0 => Ok(Some(value.unsigned_abs())),
+            Some(_) => Err(format!(
+                "`{}` must be a non-negative integer.",
+                self.qualified(key)
+            )),
+        }
+    }
+
+    /// Fails if the table has keys that were not read.
+    pub fn finish(self) -> Result<(), String> {
+        let mut unknown: Vec<String> = self.values.keys().map(|k| self.qualified(k)).collect();
+        unknown.sort();
+
+        match unknown.first() {
+            None => Ok(()),
+            Some(key) => Err(format!("unknown key `{key}`.")),
+        }
+    }
+}
+
+/// Parses a document into its tables, in the order they appear.
+pub fn parse_tables(s: &str) -> Result<Vec<Table>, String> {
+    let mut tables = vec![Table::new("")];
+
+    for (i, line) in s.lines().enumerate() {
+        let at_line = |e: String| format!("line {}: {e}", i + 1);
+        let line = strip_comment(line).trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .map(str::trim)
+                .filter(|name| is_bare_key(name))
+                .ok_or_else(|| at_line(format!("invalid table header `{line}`.")))?;
+
+            if tables.iter().any(|table| table.name == name) {
+                return Err(at_line(format!("duplicate table `[{name}]`.")));
+            }
+
+            tables.push(Table::new(name));
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .filter(|(key, _)| is_bare_key(key))
+            .ok_or_else(|| at_line(format!("expected `key = value`, got `{line}`.")))?;
+
+        let value = parse_value(value).map_err(at_line)?;
+        let table = tables.last_mut().unwrap();
+
+        if table.values.insert(key.into(), value).is_some() {
+            return Err(at_line(format!(
+                "duplicate key `{}`.",
+                table.qualified(key)
+            )));
+        }
+    }
+
+    Ok(tables)
+}
+
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Removes a `#` comment that is not part of a string.
+fn strip_comment(line: &str) -> &str {
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+
+    line
+}
+
+fn parse_value(value: &str) -> Result<Value, String> {
+    if let Some(literal) = value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+        return Ok(Value::String(literal.into()));
+    }
+
+    if let Some(basic) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        return unescape(basic).map(Value::String);
+    }
+
+    match value {
+        "true" => return Ok(Value::Boolean(true)),
+        "false" => return Ok(Value::Boolean(false)),
+        _ => {}
+    }
+
+    // `f64` would also accept e.g. `inf`, which TOML spells differently.
+    let is_number = value
+        .trim_start_matches(['+', '-'])
+        .starts_with(|c: char| c.is_ascii_digit());
+    let digits = value.replace('_', "");
+
+    let number = if !is_number {
+        None
+    } else if let Ok(integer) = digits.parse::<i64>() {
+        Some(Value::Integer(integer))
+    } else {
+        digits
+            .contains(['.', 'e', 'E'])
+            .then(|| digits.parse::<f64>().ok().map(Value::Float))
+            .flatten()
+    };
+
+    number.ok_or(format!(
+        "unsupported value `{value}`, expected a quoted string, a number or a boolean."
+    ))
+}
+
+fn unescape(s: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('\\') => result.push('\\'),
+            Some('"') => result.push('"'),
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            other => {
+                let escape = other.map_or(String::new(), String::from);
+                return Err(format!("unsupported escape sequence `\\{escape}`."));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_tables, Value};
+
+    #[test]
+    fn parses_values() {
+        let tables = parse_tables(
+            r#"
+# comment
+name = "day # one" # trailing comment
+path = 'C:\data'
+escaped = "a \"b\"\n"
+
+[table]
+count = 1_000
+ratio = -0.5
+enabled = true
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(tables.len(), 2);
+        let (root, table) = (&tables[0].values, &tables[1].values);
+        assert_eq!(root["name"], Value::String("day # one".into()));
+        assert_eq!(root["path"], Value::String("C:\\data".into()));
+        assert_eq!(root["escaped"], Value::String("a \"b\"\n".into()));
+        assert_eq!(tables[1].name, "table");
+        assert_eq!(table["count"], Value::Integer(1000));
+        assert_eq!(table["ratio"], Value::Float(-0.5));
+        assert_eq!(table["enabled"], Value::Boolean(true));
+    }
+
+    #[test]
+    fn distinguishes_integers_from_floats() {
+        let mut tables = parse_tables("a = 10\nb = 1.0\nc = 1e3\nd = -2").unwrap();
+        let table = &mut tables[0];
+
+        assert_eq!(table.values["b"], Value::Float(1.0));
+        assert_eq!(table.values["c"], Value::Float(1000.0));
+        assert_eq!(table.integer("a"), Ok(Some(10)));
+        assert_eq!(
+            table.integer("b"),
+            Err("`b` must be a non-negative integer.".into())
+        );
+        assert_eq!(
+            table.integer("d"),
+            Err("`d` must be a non-negative integer.".into())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_lines() {
+        let error = |s: &str| parse_tables(s).err().unwrap();
+
+        assert_eq!(
+            error("a = 1\nb = nan"),
+            "line 2: unsupported value `nan`, expected a quoted string, a number or a boolean."
+        );
+        assert_eq!(error("[bench"), "line 1: invalid table header `[bench`.");
+        assert_eq!(error("a = 1\na = 2"), "line 2: duplicate key `a`.");
+        assert_eq!(
+            error("just text"),
+            "line 1: expected `key = value`, got `just text`."
+        );
+    }
+}
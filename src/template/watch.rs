@@ -0,0 +1,131 @@
+/// Module that detects changes to the files a solution depends on by polling their modification times.
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{Day, Scope};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Modification times of all watched files at one point in time.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Watches the solution bin of a day, the shared `src/util` module and the day's example files.
+pub struct Watcher {
+    bin_path: PathBuf,
+    util_dir: PathBuf,
+    examples_dir: PathBuf,
+    example_prefix: String,
+    snapshot: Snapshot,
+}
+
+impl Watcher {
+    pub fn new(scope: Scope, day: Day) -> Self {
+        let mut watcher = Watcher {
+            bin_path: scope.bin_path(day).into(),
+            util_dir: "./src/util".into(),
+            examples_dir: scope.data_path("examples", "").into(),
+            example_prefix: day.to_string(),
+            snapshot: Snapshot::new(),
+        };
+        watcher.snapshot = watcher.take_snapshot();
+        watcher
+    }
+
+    /// Blocks until one of the watched files was created, modified or deleted.
+    pub fn wait_for_change(&mut self) {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let snapshot = self.take_snapshot();
+            if snapshot != self.snapshot {
+                self.snapshot = snapshot;
+                return;
+            }
+        }
+    }
+
+    fn take_snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new();
+
+        insert_mtime(&mut snapshot, &self.bin_path);
+        collect_dir(&mut snapshot, &self.util_dir);
+
+        if let Ok(entries) = fs::read_dir(&self.examples_dir) {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| self.is_example(path))
+                .for_each(|path| insert_mtime(&mut snapshot, &path));
+        }
+
+        snapshot
+    }
+
+    /// Matches the example files of the day, e.g. `01.txt` and `01-2.txt`, and its example manifest.
+    fn is_example(&self, path: &Path) -> bool {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(&self.example_prefix))
+            .is_some_and(|rest| {
+                rest == ".manifest"
+                    || rest
+                        .strip_suffix(".txt")
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+            })
+    }
+}
+
+fn insert_mtime(snapshot: &mut Snapshot, path: &Path) {
+    if let Ok(mtime) = fs::metadata(path).and_then(|metadata| metadata.modified()) {
+        snapshot.insert(path.to_path_buf(), mtime);
+    }
+}
+
+fn collect_dir(snapshot: &mut Snapshot, dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        if path.is_dir() {
+            collect_dir(snapshot, &path);
+        } else {
+            insert_mtime(snapshot, &path);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::Watcher;
+    use crate::{day, template::Scope};
+
+    #[test]
+    fn matches_examples_of_day() {
+        let watcher = Watcher::new(Scope::Flat, day!(1));
+        assert!(watcher.is_example(Path::new("data/examples/01.txt")));
+        assert!(watcher.is_example(Path::new("data/examples/01-2.txt")));
+        assert!(watcher.is_example(Path::new("data/examples/01.manifest")));
+        assert!(!watcher.is_example(Path::new("data/examples/11.txt")));
+        assert!(!watcher.is_example(Path::new("data/examples/01.md")));
+    }
+
+    #[test]
+    fn watches_util_module() {
+        let watcher = Watcher::new(Scope::Flat, day!(1));
+        assert!(watcher
+            .snapshot
+            .keys()
+            .any(|path| path.ends_with("src/util/mod.rs")));
+        assert!(watcher
+            .snapshot
+            .keys()
+            .any(|path| path.ends_with("src/bin/01.rs")));
+    }
+}